@@ -0,0 +1,84 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use ease_off::{EaseOff, Options};
+use std::convert::Infallible;
+use std::hint::black_box;
+use std::time::Duration;
+
+const NO_JITTER: Options = Options::new()
+    .jitter(0.0)
+    .initial_jitter(0.0)
+    .initial_delay(Duration::ZERO);
+
+const JITTER: Options = Options::new()
+    .jitter(0.25)
+    .initial_jitter(0.25)
+    .initial_delay(Duration::ZERO);
+
+fn try_blocking_ok(ease_off: &mut EaseOff<Infallible>) {
+    let res = ease_off
+        .try_blocking(|| Ok::<_, Infallible>(black_box(1)))
+        .or_retry_if(|_| true);
+
+    black_box(res).unwrap();
+}
+
+fn try_blocking_err(ease_off: &mut EaseOff<u32>) {
+    let res = ease_off
+        .try_blocking(|| Err::<(), _>(black_box(1)))
+        .or_retry_if(|_| true);
+
+    black_box(res).unwrap();
+}
+
+fn bench_next_retry_at(c: &mut Criterion) {
+    let mut group = c.benchmark_group("next_retry_at");
+
+    for (name, options) in [("no-jitter", NO_JITTER), ("jitter", JITTER)] {
+        group.bench_function(format!("{name}/success"), |b| {
+            let mut ease_off = options.start_unlimited();
+            b.iter(|| try_blocking_ok(&mut ease_off))
+        });
+
+        group.bench_function(format!("{name}/failure"), |b| {
+            let mut ease_off = options.clone().max_delay(Duration::ZERO).start_unlimited();
+            b.iter(|| try_blocking_err(&mut ease_off))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_start(c: &mut Criterion) {
+    let mut group = c.benchmark_group("start");
+
+    group.bench_function("options", |b| {
+        b.iter(|| black_box(&JITTER).start_timeout::<u32>(Duration::from_secs(1)))
+    });
+
+    let core = JITTER.into_core();
+
+    group.bench_function("core", |b| {
+        b.iter(|| black_box(&core).start_timeout::<u32>(Duration::from_secs(1)))
+    });
+
+    group.bench_function("reset", |b| {
+        let mut ease_off = JITTER.start_timeout::<u32>(Duration::from_secs(1));
+        b.iter(|| black_box(&mut ease_off).reset())
+    });
+
+    group.finish();
+}
+
+fn bench_now(c: &mut Criterion) {
+    let mut group = c.benchmark_group("now");
+
+    group.bench_function("std", |b| b.iter(std::time::Instant::now));
+
+    // Uses `quanta` if the feature is enabled.
+    group.bench_function("ease_off", |b| b.iter(ease_off::now));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_next_retry_at, bench_start, bench_now);
+criterion_main!(benches);
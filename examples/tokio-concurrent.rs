@@ -13,7 +13,7 @@ struct Operation {
     // continues too long.
     num_attempts: Saturating<u32>,
     threshold: u32,
-    deadline: Instant,
+    started_at: Instant,
 }
 
 const OPERATION_TIMEOUT: Duration = Duration::from_secs(30);
@@ -38,7 +38,7 @@ async fn main() {
         .map(|i| Operation {
             num_attempts: Saturating(0),
             threshold: i,
-            deadline: started_at + OPERATION_TIMEOUT,
+            started_at,
         })
         .collect::<Vec<_>>();
 
@@ -82,7 +82,13 @@ async fn main() {
             Err(_) => {
                 let attempt_num = operation.num_attempts.0;
 
-                match EASE_OFF.nth_retry_at(attempt_num, now, Some(operation.deadline), &mut rng) {
+                match EASE_OFF.nth_retry_at_from(
+                    attempt_num,
+                    operation.started_at,
+                    now,
+                    Some(OPERATION_TIMEOUT),
+                    &mut rng,
+                ) {
                     Ok(Some(retry_at)) => {
                         scheduled_attempts.insert_at(i, retry_at.into());
                     }
@@ -0,0 +1,72 @@
+//! The time source used for all internal reads of the current time.
+//!
+//! With the `quanta` feature enabled, the current time is read using [`quanta`],
+//! which uses the CPU's Time Stamp Counter (TSC) where available
+//! and is significantly cheaper to read than [`Instant::now()`] on most platforms.
+//! The result is still converted to [`Instant`] for use with the public API.
+//!
+//! This is a crate-wide switch rather than a pluggable `Clock` trait: the time is also read
+//! where there is no [`EaseOff`][crate::EaseOff] to carry a clock (e.g. [`RetryLimiter`]
+//! and `Retry-After` parsing), and a dynamic call on every read would give back much of what
+//! `quanta` saves.
+//!
+//! [`RetryLimiter`]: crate::RetryLimiter
+
+use std::time::{Duration, Instant};
+
+/// Returns the current time, as read by this crate.
+///
+/// This is [`Instant::now()`], unless the `quanta` feature is enabled.
+/// Pass it to the `*_at()` methods, e.g. [`EaseOff::try_blocking_at()`], so they see
+/// the same clock as the rest of the crate.
+///
+/// [`EaseOff::try_blocking_at()`]: crate::EaseOff::try_blocking_at
+#[cfg(not(feature = "quanta"))]
+#[inline(always)]
+pub fn now() -> Instant {
+    Instant::now()
+}
+
+/// Returns the current time, as read by this crate.
+///
+/// Pass it to the `*_at()` methods, e.g. [`EaseOff::try_blocking_at()`], so they see
+/// the same clock as the rest of the crate.
+///
+/// Measured using [`quanta`], relative to an anchor taken using [`Instant::now()`]
+/// on first use. `quanta` calibrates its clock against the OS's monotonic clock,
+/// so any drift between the two should be negligible for the purposes of backoff.
+///
+/// [`EaseOff::try_blocking_at()`]: crate::EaseOff::try_blocking_at
+#[cfg(feature = "quanta")]
+pub fn now() -> Instant {
+    use std::sync::OnceLock;
+
+    static ANCHOR: OnceLock<(Instant, quanta::Instant)> = OnceLock::new();
+
+    let (instant, quanta_instant) = ANCHOR.get_or_init(|| (Instant::now(), quanta::Instant::now()));
+
+    *instant + quanta::Instant::now().duration_since(*quanta_instant)
+}
+
+/// Add `duration` to `instant`, saturating at the latest representable [`Instant`].
+///
+/// `Instant` has no `MAX` constant, so on overflow it is found by bisection.
+pub(crate) fn saturating_add(instant: Instant, duration: Duration) -> Instant {
+    if let Some(sum) = instant.checked_add(duration) {
+        return sum;
+    }
+
+    let (mut lo, mut hi) = (Duration::ZERO, duration);
+
+    while hi - lo > Duration::from_nanos(1) {
+        let mid = lo + (hi - lo) / 2;
+
+        if instant.checked_add(mid).is_some() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    instant + lo
+}
@@ -0,0 +1,87 @@
+//! Helpers for retrying operations which fail with raw integer error codes.
+//!
+//! This is intended for wrapping C APIs and other interfaces which report errors as plain
+//! integers, e.g. `errno` values, Win32 error codes or `HRESULT`s. It parallels the `http` module,
+//! but the set of retryable codes is always supplied by the caller,
+//! as there is no meaningful default across APIs.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use ease_off::code::CodeError;
+//! use ease_off::EaseOff;
+//!
+//! // `EAGAIN`, `EINTR` and `EBUSY` on Linux.
+//! const RETRYABLE_CODES: &[i32] = &[11, 4, 16];
+//!
+//! // Pretend return codes from a C function.
+//! let mut codes = vec![0, 4, 11];
+//!
+//! let mut ease_off = EaseOff::start_timeout(Duration::from_secs(30));
+//!
+//! loop {
+//!     let Some(()) = ease_off
+//!         .try_blocking(|| match codes.pop().unwrap() {
+//!             0 => Ok(()),
+//!             code => Err(CodeError::new(code, RETRYABLE_CODES)),
+//!         })
+//!         .or_retry()?
+//!     else {
+//!         continue;
+//!     };
+//!
+//!     break;
+//! }
+//!
+//! assert_eq!(ease_off.num_attempts(), 2);
+//!
+//! // Codes not in the set are fatal.
+//! let result = EaseOff::start_unlimited()
+//!     .try_blocking(|| Err::<(), _>(CodeError::new(13, RETRYABLE_CODES)))
+//!     .or_retry();
+//!
+//! assert_eq!(result.unwrap_err().code, 13);
+//! # Ok::<_, CodeError>(())
+//! ```
+
+use crate::RetryableError;
+use std::fmt;
+
+/// An integer error code, implementing [`RetryableError`] based on a set of retryable codes.
+///
+/// ### Comparison Semantics
+/// [`RetryableError::can_retry()`] returns `true` if [`Self::code`] is equal
+/// (according to [`PartialEq`]) to any element of [`Self::retryable_codes`].
+/// The codes are compared as whole values, in a linear scan of the slice:
+/// * There is no support for ranges; list every retryable code.
+/// * Codes which pack several fields into one integer (such as `HRESULT`, which includes
+///   a severity bit and a facility) are not decomposed, so list the complete values.
+/// * No conversion is made between signed and unsigned representations;
+///   use the same type (and sign convention) for the code and the set.
+///
+/// As the set is a `&'static` slice, it can be a `const` or `static` item,
+/// so no allocation or setup is needed to construct an error.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("error code {code}")]
+#[non_exhaustive]
+pub struct CodeError<C: 'static = i32> {
+    /// The error code returned by the operation.
+    pub code: C,
+    /// The codes considered retryable.
+    pub retryable_codes: &'static [C],
+}
+
+impl<C: 'static> CodeError<C> {
+    /// Create an error for the given code, which is retryable if it is in `retryable_codes`.
+    pub fn new(code: C, retryable_codes: &'static [C]) -> Self {
+        Self {
+            code,
+            retryable_codes,
+        }
+    }
+}
+
+impl<C: PartialEq + fmt::Display + 'static> RetryableError for CodeError<C> {
+    fn can_retry(&self) -> bool {
+        self.retryable_codes.contains(&self.code)
+    }
+}
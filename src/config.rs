@@ -0,0 +1,234 @@
+use crate::{InvalidOptions, Options};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Serializable representation of [`Options`], for loading from configuration files.
+///
+/// This is kept separate from [`Options`] so the wire format can evolve independently
+/// of the runtime type. Convert using [`Options::try_from()`] (which validates the values)
+/// and [`OptionsConfig::from()`].
+///
+/// Every field is optional when deserializing; missing fields are taken from
+/// [`Options::DEFAULT`]. Durations are written in [`humantime`] format,
+/// e.g. `"150ms"` or `"1m 30s"`.
+///
+/// | Field                           | Option                                                            |
+/// |---------------------------------|-------------------------------------------------------------------|
+/// | `multiplier`                    | [`Options::multiplier()`]                                         |
+/// | `jitter`                        | [`Options::jitter()`]                                             |
+/// | `initial_jitter`                | [`Options::initial_jitter()`]                                     |
+/// | `initial_immediate_probability` | [`Options::initial_immediate_probability()`] (omitted if not set) |
+/// | `initial_delay`                 | [`Options::initial_delay()`]                                      |
+/// | `max_delay`                     | [`Options::max_delay()`]                                          |
+/// | `fast_first_retry`              | [`Options::fast_first_retry()`] (omitted if not set)              |
+/// | `allow_zero_attempts`           | [`Options::allow_zero_attempts()`]                                |
+/// | `reset_on_success`              | [`Options::reset_on_success()`]                                   |
+/// | `deadline_jitter`               | [`Options::deadline_jitter()`]                                    |
+/// | `deadline_excludes_sleep`       | [`Options::deadline_excludes_sleep()`]                            |
+/// | `base_spread`                   | [`Options::base_spread()`]                                        |
+/// | `soft_deadline`                 | [`Options::soft_deadline()`] (omitted if not set)                 |
+/// | `quantize_delay`                | [`Options::quantize_delay()`] (omitted if not set)                |
+/// | `max_consecutive_timeouts`      | [`Options::max_consecutive_timeouts()`] (omitted if not set)      |
+/// | `max_distinct_errors`           | [`Options::max_distinct_errors()`] (omitted if not set)           |
+/// | `history_capacity`              | [`Options::history_capacity()`] (omitted if not set)              |
+/// | `delay_overrides`               | [`Options::delay_override()`] (omitted if empty)                  |
+///
+/// [`humantime`]: https://docs.rs/humantime
+///
+/// ```rust
+/// use ease_off::{Options, OptionsConfig};
+/// use std::time::Duration;
+///
+/// let config: OptionsConfig = serde_json::from_str(
+///     r#"{ "initial_delay": "1s", "max_delay": "5m", "jitter": 0.5 }"#
+/// ).unwrap();
+///
+/// let options = Options::try_from(config).unwrap();
+///
+/// assert_eq!(options.get_initial_delay(), Duration::from_secs(1));
+/// assert_eq!(options.get_max_delay(), Duration::from_secs(5 * 60));
+/// assert_eq!(options.get_jitter(), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+#[non_exhaustive]
+pub struct OptionsConfig {
+    /// See [`Options::multiplier()`].
+    pub multiplier: f32,
+    /// See [`Options::jitter()`].
+    pub jitter: f32,
+    /// See [`Options::initial_jitter()`].
+    pub initial_jitter: f32,
+    /// See [`Options::initial_immediate_probability()`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_immediate_probability: Option<f32>,
+    /// See [`Options::initial_delay()`].
+    #[serde(with = "humantime_serde")]
+    pub initial_delay: Duration,
+    /// See [`Options::max_delay()`].
+    #[serde(with = "humantime_serde")]
+    pub max_delay: Duration,
+    /// See [`Options::fast_first_retry()`].
+    #[serde(with = "humantime_serde", skip_serializing_if = "Option::is_none")]
+    pub fast_first_retry: Option<Duration>,
+    /// See [`Options::allow_zero_attempts()`].
+    pub allow_zero_attempts: bool,
+    /// See [`Options::reset_on_success()`].
+    pub reset_on_success: bool,
+    /// See [`Options::deadline_jitter()`].
+    pub deadline_jitter: f32,
+    /// See [`Options::deadline_excludes_sleep()`].
+    pub deadline_excludes_sleep: bool,
+    /// See [`Options::base_spread()`].
+    pub base_spread: f32,
+    /// See [`Options::soft_deadline()`].
+    #[serde(with = "humantime_serde", skip_serializing_if = "Option::is_none")]
+    pub soft_deadline: Option<Duration>,
+    /// See [`Options::quantize_delay()`].
+    #[serde(with = "humantime_serde", skip_serializing_if = "Option::is_none")]
+    pub quantize_delay: Option<Duration>,
+    /// See [`Options::max_consecutive_timeouts()`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_consecutive_timeouts: Option<u32>,
+    /// See [`Options::max_distinct_errors()`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distinct_errors: Option<u32>,
+    /// See [`Options::history_capacity()`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_capacity: Option<usize>,
+    /// See [`Options::delay_override()`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub delay_overrides: Vec<DelayOverrideConfig>,
+}
+
+/// Serializable representation of an [`Options::delay_override()`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct DelayOverrideConfig {
+    /// The index of the attempt to override the delay for.
+    pub attempt: u32,
+    /// The delay before the attempt.
+    #[serde(with = "humantime_serde")]
+    pub delay: Duration,
+}
+
+impl Default for OptionsConfig {
+    /// Returns the equivalent of [`Options::DEFAULT`].
+    fn default() -> Self {
+        Options::DEFAULT.into()
+    }
+}
+
+impl From<Options> for OptionsConfig {
+    fn from(options: Options) -> Self {
+        Self {
+            multiplier: options.multiplier,
+            jitter: options.jitter,
+            initial_jitter: options.initial_jitter,
+            initial_immediate_probability: options.initial_immediate_probability,
+            initial_delay: options.initial_delay,
+            max_delay: options.max_delay,
+            fast_first_retry: options.fast_first_retry,
+            allow_zero_attempts: options.allow_zero_attempts,
+            reset_on_success: options.reset_on_success,
+            deadline_jitter: options.deadline_jitter,
+            deadline_excludes_sleep: options.deadline_excludes_sleep,
+            base_spread: options.base_spread,
+            soft_deadline: options.soft_deadline,
+            quantize_delay: options.quantize_delay,
+            max_consecutive_timeouts: options.max_consecutive_timeouts,
+            max_distinct_errors: options.max_distinct_errors,
+            history_capacity: options.history_capacity,
+            delay_overrides: options
+                .delay_overrides
+                .iter()
+                .flatten()
+                .map(|&(attempt, delay)| DelayOverrideConfig { attempt, delay })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<OptionsConfig> for Options {
+    type Error = InvalidOptions;
+
+    fn try_from(config: OptionsConfig) -> Result<Self, Self::Error> {
+        let OptionsConfig {
+            multiplier,
+            jitter,
+            initial_jitter,
+            initial_immediate_probability,
+            initial_delay,
+            max_delay,
+            fast_first_retry,
+            allow_zero_attempts,
+            reset_on_success,
+            deadline_jitter,
+            deadline_excludes_sleep,
+            base_spread,
+            soft_deadline,
+            quantize_delay,
+            max_consecutive_timeouts,
+            max_distinct_errors,
+            history_capacity,
+            delay_overrides,
+        } = config;
+
+        let mut attempts: Vec<u32> = delay_overrides.iter().map(|o| o.attempt).collect();
+        attempts.sort_unstable();
+        attempts.dedup();
+
+        if attempts.len() > Options::MAX_DELAY_OVERRIDES {
+            return Err(InvalidOptions::TooManyDelayOverrides(attempts.len()));
+        }
+
+        let mut options = Options::new()
+            .multiplier(multiplier)
+            .jitter(jitter)
+            .initial_jitter(initial_jitter)
+            .initial_delay(initial_delay)
+            .max_delay(max_delay)
+            .allow_zero_attempts(allow_zero_attempts)
+            .reset_on_success(reset_on_success)
+            .deadline_jitter(deadline_jitter)
+            .deadline_excludes_sleep(deadline_excludes_sleep)
+            .base_spread(base_spread);
+
+        if let Some(probability) = initial_immediate_probability {
+            options = options.initial_immediate_probability(probability);
+        }
+
+        if let Some(fast_first_retry) = fast_first_retry {
+            options = options.fast_first_retry(fast_first_retry);
+        }
+
+        if let Some(soft_deadline) = soft_deadline {
+            options = options.soft_deadline(soft_deadline);
+        }
+
+        if let Some(quantum) = quantize_delay {
+            options = options.quantize_delay(quantum);
+        }
+
+        if let Some(max) = max_consecutive_timeouts {
+            options = options.max_consecutive_timeouts(max);
+        }
+
+        if let Some(max) = max_distinct_errors {
+            options = options.max_distinct_errors(max);
+        }
+
+        if let Some(capacity) = history_capacity {
+            options = options.history_capacity(capacity);
+        }
+
+        for DelayOverrideConfig { attempt, delay } in delay_overrides {
+            options = options.delay_override(attempt, delay);
+        }
+
+        options.validate()?;
+
+        Ok(options)
+    }
+}
@@ -18,8 +18,11 @@
 #![doc = "```"]
 
 use crate::options::Options;
+use crate::{clock, rng, DelaySummary, EaseOff};
 use rand::Rng;
 use std::cmp;
+use std::collections::VecDeque;
+use std::num::Saturating;
 use std::time::{Duration, Instant};
 
 /// Immutable core backoff API, without error management or sleeps.
@@ -40,6 +43,19 @@ pub struct RetryAfterDeadline {
     pub deadline: Instant,
 }
 
+/// A retry scheduled by [`EaseOffCore::nth_retry_at_detailed()`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ScheduledRetry {
+    /// The recommended time for the backoff attempt.
+    pub retry_at: Instant,
+    /// The delay between `now` and `retry_at`, with jitter applied.
+    pub delay: Duration,
+    /// `true` if the delay before jitter was capped at [`Options::max_delay`],
+    /// i.e. the backoff has plateaued.
+    pub clamped_to_max: bool,
+}
+
 impl EaseOffCore {
     /// Create an instance from a built [`Options`].
     ///
@@ -49,11 +65,72 @@ impl EaseOffCore {
         Self { options }
     }
 
+    /// Get the [`Options`] this instance was created with.
+    #[inline(always)]
+    pub const fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Returns an instance with its [`Options`] modified by `f`.
+    ///
+    /// Useful for deriving related policies from a shared base; for the most common options,
+    /// the shorthands [`Self::with_max_delay()`], [`Self::with_jitter()`]
+    /// and [`Self::with_multiplier()`] may also be used in `const`s.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::core::EaseOffCore;
+    /// use ease_off::Options;
+    ///
+    /// const BASE: EaseOffCore = Options::new()
+    ///     .initial_delay(Duration::from_millis(100))
+    ///     .into_core();
+    ///
+    /// // Back off further for a slow dependency.
+    /// const SLOW: EaseOffCore = BASE.with_max_delay(Duration::from_secs(5 * 60));
+    ///
+    /// let fast = BASE.with_options(|options| options.fast_first_retry(Duration::from_millis(5)));
+    ///
+    /// assert_eq!(SLOW.options().get_initial_delay(), Duration::from_millis(100));
+    /// assert_eq!(SLOW.options().get_max_delay(), Duration::from_secs(5 * 60));
+    /// assert_eq!(fast.options().get_fast_first_retry(), Some(Duration::from_millis(5)));
+    /// ```
+    #[inline(always)]
+    pub fn with_options(self, f: impl FnOnce(Options) -> Options) -> Self {
+        Self::new(f(self.options))
+    }
+
+    /// Returns an instance with [`Options::max_delay()`] set.
+    #[inline(always)]
+    pub const fn with_max_delay(self, max_delay: Duration) -> Self {
+        Self::new(self.options.max_delay(max_delay))
+    }
+
+    /// Returns an instance with [`Options::jitter()`] set.
+    #[inline(always)]
+    pub const fn with_jitter(self, jitter: f32) -> Self {
+        Self::new(self.options.jitter(jitter))
+    }
+
+    /// Returns an instance with [`Options::multiplier()`] set.
+    #[inline(always)]
+    pub const fn with_multiplier(self, multiplier: f32) -> Self {
+        Self::new(self.options.multiplier(multiplier))
+    }
+
     /// Returns the recommended [`Instant`] at which to schedule the `n`th backoff attempt.
     ///
-    /// Returns `Ok(None)` if `n == 0` and [`Options::initial_jitter`] is not greater than zero.
+    /// Returns `Ok(None)` if `n == 0`, [`Options::initial_jitter`] is not greater than zero,
+    /// [`Options::initial_immediate_probability`] is not set,
+    /// and no [override][Options::delay_override] is set for attempt `0`.
+    /// If `initial_immediate_probability` is set, `Ok(None)` is returned for `n == 0`
+    /// with that probability.
     ///
     /// Returns `Err` if the calculated [`Instant`] falls after `deadline`.
+    ///
+    /// `rng` is not used if the applicable jitter factor is not greater than zero
+    /// (and `initial_immediate_probability` is not set, for `n == 0`),
+    /// so it may be initialized lazily if acquiring it is expensive.
     pub fn nth_retry_at(
         &self,
         n: u32,
@@ -61,47 +138,618 @@ impl EaseOffCore {
         deadline: Option<Instant>,
         rng: &mut (impl Rng + ?Sized),
     ) -> Result<Option<Instant>, RetryAfterDeadline> {
+        self.nth_retry_at_detailed(n, now, deadline, rng)
+            .map(|retry| retry.map(|retry| retry.retry_at))
+    }
+
+    /// Like [`Self::nth_retry_at()`], but with the deadline given as a `timeout`
+    /// relative to when the operation started, as for [`Options::start_timeout_opt()`].
+    ///
+    /// The deadline is calculated as `started_at + timeout`, saturating on overflow.
+    /// Unlike [`EaseOff`], [`Options::deadline_jitter`] is _not_ applied, as it would be
+    /// chosen differently on every call; subtract it from `timeout` up front if desired.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new().into_core();
+    /// let started_at = Instant::now();
+    /// let timeout = Some(Duration::from_secs(30));
+    ///
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let retry_at = core
+    ///     .nth_retry_at_from(1, started_at, started_at, timeout, &mut rng)
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert!(retry_at > started_at);
+    ///
+    /// // 29 seconds later, the next retry would be after the deadline.
+    /// let now = started_at + Duration::from_secs(29);
+    ///
+    /// let err = core
+    ///     .nth_retry_at_from(5, started_at, now, timeout, &mut rng)
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.deadline, started_at + Duration::from_secs(30));
+    /// ```
+    pub fn nth_retry_at_from(
+        &self,
+        n: u32,
+        started_at: Instant,
+        now: Instant,
+        timeout: Option<Duration>,
+        rng: &mut (impl Rng + ?Sized),
+    ) -> Result<Option<Instant>, RetryAfterDeadline> {
+        let deadline = timeout.map(|timeout| clock::saturating_add(started_at, timeout));
+
+        self.nth_retry_at(n, now, deadline, rng)
+    }
+
+    /// Like [`Self::nth_retry_at()`], but also returns the delay and whether it was capped
+    /// at [`Options::max_delay`].
+    ///
+    /// Useful for diagnostics, e.g. to tell when the backoff has plateaued
+    /// rather than still growing.
+    pub fn nth_retry_at_detailed(
+        &self,
+        n: u32,
+        now: Instant,
+        deadline: Option<Instant>,
+        rng: &mut (impl Rng + ?Sized),
+    ) -> Result<Option<ScheduledRetry>, RetryAfterDeadline> {
+        let Some((delay, clamped_to_max)) = self.nth_delay(n, rng) else {
+            return Ok(None);
+        };
+
+        let retry_at = now + delay;
+
+        match deadline {
+            Some(deadline) if retry_at > deadline => Err(RetryAfterDeadline {
+                n,
+                retry_at,
+                deadline,
+            }),
+            _ => Ok(Some(ScheduledRetry {
+                retry_at,
+                delay,
+                clamped_to_max,
+            })),
+        }
+    }
+
+    /// Returns the bounds `(min, max)` of the delay before the `n`th backoff attempt,
+    /// given the jitter configuration, without using a random number generator.
+    ///
+    /// Useful to show an honest range, e.g. "next retry in 3–4 seconds", rather than
+    /// a single number that jitter would make inaccurate.
+    ///
+    /// Jitter is only ever _subtracted_ from the calculated delay (see [`Options::jitter()`]),
+    /// so `max` is the delay before jitter, capped at [`Options::max_delay`], and `min` is:
+    ///
+    /// | Applicable jitter factor        | `min`                   |
+    /// |---------------------------------|-------------------------|
+    /// | `<= 0` or `NaN` (no jitter)     | `max`                   |
+    /// | between `0` and `1`             | `max * (1 - jitter)`    |
+    /// | `>= 1` (full jitter)            | [`Duration::ZERO`]      |
+    ///
+    /// The applicable factor is [`Options::initial_jitter`] for `n == 0` and
+    /// [`Options::jitter`] otherwise; [overrides][Options::delay_override] are never jittered.
+    /// The delays returned by [`Self::nth_retry_at()`] fall in `(min, max]` when jittered,
+    /// allowing for floating-point rounding.
+    ///
+    /// For `n == 0`, `min` is [`Duration::ZERO`] if [`Options::initial_immediate_probability`]
+    /// is greater than zero, as the attempt may then be made immediately.
+    ///
+    /// Both bounds are rounded up as set by [`Options::quantize_delay`], if set,
+    /// so the delay may then be equal to `min`.
+    ///
+    /// Returns `(Duration::ZERO, Duration::ZERO)` if the attempt would not be delayed.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new()
+    ///     .initial_delay(Duration::from_secs(1))
+    ///     .jitter(0.25)
+    ///     .into_core();
+    ///
+    /// // The first retry.
+    /// assert_eq!(
+    ///     core.delay_bounds(1),
+    ///     (Duration::from_millis(750), Duration::from_secs(1)),
+    /// );
+    ///
+    /// let (min, max) = core.delay_bounds(3);
+    /// println!("next retry in {:.1}–{:.1} seconds", min.as_secs_f32(), max.as_secs_f32());
+    ///
+    /// // No initial jitter by default, so the first attempt is made immediately.
+    /// assert_eq!(core.delay_bounds(0), (Duration::ZERO, Duration::ZERO));
+    /// ```
+    pub fn delay_bounds(&self, n: u32) -> (Duration, Duration) {
+        let Some(BaseDelay { delay, jitter, .. }) = self.nth_base_delay(n) else {
+            return (Duration::ZERO, Duration::ZERO);
+        };
+
+        if let (0, Some(probability)) = (n, self.options.initial_immediate_probability) {
+            if probability > 0f32 {
+                return (Duration::ZERO, self.quantize(delay));
+            }
+        }
+
+        let jitter = if jitter >= 1f32 {
+            1f32
+        } else if jitter > 0f32 {
+            jitter
+        } else {
+            // `jitter` is NaN or <= 0
+            0f32
+        };
+
+        (
+            self.quantize(delay.saturating_sub(duration_saturating_mul_f32(delay, jitter))),
+            self.quantize(delay),
+        )
+    }
+
+    /// Round `delay` up to a multiple of [`Options::quantize_delay`], if set.
+    fn quantize(&self, delay: Duration) -> Duration {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+        let Some(quantum) = self.options.quantize_delay else {
+            return delay;
+        };
+
+        let Some(rem) = delay.as_nanos().checked_rem(quantum.as_nanos()) else {
+            // `quantum` is zero
+            return delay;
+        };
+
+        if rem == 0 {
+            return delay;
+        }
+
+        // `rem < quantum`, so this cannot truncate or underflow.
+        let rem = Duration::new((rem / NANOS_PER_SEC) as u64, (rem % NANOS_PER_SEC) as u32);
+
+        delay.saturating_add(quantum - rem)
+    }
+
+    /// Returns the recommended number of ticks to wait before the `n`th backoff attempt.
+    ///
+    /// This is for retries driven by an external counter (e.g. a heartbeat or event loop
+    /// iteration) rather than wall-clock time. The schedule is calculated exactly as in
+    /// [`Self::nth_retry_at()`], and the resulting delay is divided by `tick`,
+    /// the nominal length of one tick, rounding up.
+    ///
+    /// For example, with `tick` set to one second, [`Options::initial_delay`] of 5 seconds
+    /// translates to 5 ticks, and so on for [`Options::max_delay`].
+    /// A delay that is not a whole number of ticks is rounded up so that a retry
+    /// is never scheduled earlier than the calculated delay.
+    ///
+    /// Returns `0` whenever [`Self::nth_retry_at()`] returns `Ok(None)`.
+    ///
+    /// Saturates at [`u64::MAX`].
+    ///
+    /// ### Panics
+    /// If `tick` is zero.
+    pub fn nth_retry_ticks(&self, n: u32, tick: Duration, rng: &mut (impl Rng + ?Sized)) -> u64 {
+        assert!(!tick.is_zero(), "`tick` must not be zero");
+
+        let Some((delay, _)) = self.nth_delay(n, rng) else {
+            return 0;
+        };
+
+        delay
+            .as_nanos()
+            .div_ceil(tick.as_nanos())
+            .try_into()
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Estimate how many attempts will be made before giving up, given a timeout.
+    ///
+    /// Sums the delays before each attempt, without [jitter][Options::jitter],
+    /// until the total exceeds `timeout`. The initial attempt is always counted.
+    ///
+    /// Attempts are counted the way [`EaseOff`] makes them, which delays both the initial
+    /// attempt and the first retry by the delay for `n = 0` (zero unless
+    /// [`Options::initial_jitter()`] is set), and the `k`th retry by the delay for `n = k - 1`.
+    ///
+    /// This is only an estimate for capacity planning:
+    /// * Jitter only ever shortens delays, so it may allow slightly more attempts.
+    /// * The time taken by the operation itself is not counted, which may allow fewer.
+    ///
+    /// Saturates at [`u32::MAX`], e.g. if the delays are all zero.
+    ///
+    /// See also [`Self::max_total_time()`] for the inverse.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new()
+    ///     .initial_delay(Duration::from_secs(1))
+    ///     .multiplier(2.0)
+    ///     .max_delay(Duration::from_secs(4))
+    ///     .into_core();
+    ///
+    /// // Delays before each attempt: 0s, 0s, 1s, 2s, 4s, 4s, ...
+    /// assert_eq!(core.expected_attempts(Duration::from_secs(6)), 4);
+    /// assert_eq!(core.expected_attempts(Duration::from_secs(7)), 5);
+    /// assert_eq!(core.expected_attempts(Duration::from_secs(15)), 7);
+    ///
+    /// assert_eq!(core.max_total_time(7), Duration::from_secs(15));
+    /// ```
+    pub fn expected_attempts(&self, timeout: Duration) -> u32 {
+        let mut elapsed = self.options.nth_unjittered_delay(0);
+        let mut attempts = 1u32;
+        // The delay before the next retry.
+        let mut n = 0u32;
+
+        loop {
+            let delay = self.options.nth_unjittered_delay(n);
+            elapsed = elapsed.saturating_add(delay);
+
+            if elapsed > timeout {
+                return attempts;
+            }
+
+            let Some(next_attempts) = attempts.checked_add(1) else {
+                return u32::MAX;
+            };
+
+            attempts = next_attempts;
+
+            if self.options.is_steady_from(n) {
+                // Every delay from here on is `delay`.
+                let more = (timeout - elapsed)
+                    .as_nanos()
+                    .checked_div(delay.as_nanos())
+                    .unwrap_or(u128::MAX);
+
+                return u32::try_from(more)
+                    .ok()
+                    .and_then(|more| attempts.checked_add(more))
+                    .unwrap_or(u32::MAX);
+            }
+
+            n += 1;
+        }
+    }
+
+    /// Returns the total delay before the first `attempts` attempts, without jitter.
+    ///
+    /// This is the longest the backoff can spend sleeping in the course of making
+    /// `attempts` attempts, not counting the time taken by the operation itself.
+    /// Attempts are counted as in [`Self::expected_attempts()`].
+    ///
+    /// See [`Self::expected_attempts()`] for the inverse.
+    /// Saturates at [`Duration::MAX`].
+    pub fn max_total_time(&self, attempts: u32) -> Duration {
+        let Some(retries) = attempts.checked_sub(1) else {
+            return Duration::ZERO;
+        };
+
+        let mut total = self.options.nth_unjittered_delay(0);
+
+        for n in 0..retries {
+            let delay = self.options.nth_unjittered_delay(n);
+            total = total.saturating_add(delay);
+
+            if self.options.is_steady_from(n) {
+                // Every delay from here on is `delay`.
+                return total
+                    .saturating_add(delay.checked_mul(retries - n - 1).unwrap_or(Duration::MAX));
+            }
+        }
+
+        total
+    }
+
+    /// Calculate the delay before the `n`th attempt as used by [`Self::nth_retry_at_detailed()`],
+    /// and whether it was capped at [`Options::max_delay`].
+    ///
+    /// Returns `None` if the attempt should not be delayed at all.
+    pub(crate) fn nth_delay(
+        &self,
+        n: u32,
+        rng: &mut (impl Rng + ?Sized),
+    ) -> Option<(Duration, bool)> {
+        if let (0, Some(probability)) = (n, self.options.initial_immediate_probability) {
+            // `NaN` fails this check
+            if rng.gen::<f32>() < probability {
+                return None;
+            }
+        }
+
+        let BaseDelay {
+            delay,
+            jitter,
+            clamped_to_max,
+        } = self.nth_base_delay(n)?;
+
+        // We only subtract jitter so that `deadline` is a hard limit
+        Some((
+            self.quantize(delay - get_jitter(delay, jitter, rng)),
+            clamped_to_max,
+        ))
+    }
+
+    /// See [`Options::nth_base_delay()`].
+    #[inline(always)]
+    pub(crate) fn nth_base_delay(&self, n: u32) -> Option<BaseDelay> {
+        self.options.nth_base_delay(n)
+    }
+}
+
+/// Delay calculations, on `Options` so they can be delegated to [`Options::with_warmup()`].
+impl Options {
+    /// Calculate the delay before the `n`th attempt, before jitter,
+    /// and the jitter factor to apply to it.
+    ///
+    /// Returns `None` if the attempt should not be delayed at all.
+    pub(crate) fn nth_base_delay(&self, n: u32) -> Option<BaseDelay> {
         let Options {
             multiplier,
             jitter,
             initial_jitter,
             initial_delay,
             max_delay,
-        } = self.options;
-
-        let (delay, jitter) = if let Some(powi) = n.checked_sub(1) {
-            let delay = cmp::min(
-                duration_saturating_mul_f32(
-                    initial_delay,
-                    multiplier.powi(powi.try_into().unwrap_or(i32::MAX)),
-                ),
-                max_delay,
-            );
+            fast_first_retry,
+            // Handled by `EaseOffCore::nth_retry_at_detailed()`
+            initial_immediate_probability,
+            quantize_delay: _,
+            // Handled by `EaseOff`
+            allow_zero_attempts: _,
+            reset_on_success: _,
+            deadline_jitter: _,
+            deadline_excludes_sleep: _,
+            base_spread: _,
+            soft_deadline: _,
+            max_consecutive_timeouts: _,
+            max_distinct_errors: _,
+            history_capacity: _,
+            warn_on_thundering_herd: _,
+            // Handled below
+            delay_overrides: _,
+            fixed_schedule,
+            warmup,
+        } = *self;
+
+        if let Some((attempts, steady)) = warmup {
+            if n >= attempts {
+                return steady.nth_base_delay(n);
+            }
+        }
 
-            let jitter = get_jitter(delay, jitter, rng);
+        if let Some(delay) = self.get_delay_override(n) {
+            // Jitter is not applied to overrides
+            Some(BaseDelay::new(delay, max_delay, 0f32))
+        } else if let (Some(schedule), Some(i)) = (fixed_schedule, n.checked_sub(1)) {
+            // Repeat the last delay once the schedule is exhausted.
+            let delay = usize::try_from(i)
+                .ok()
+                .and_then(|i| schedule.get(i))
+                .or(schedule.last())
+                .copied()
+                .unwrap_or(initial_delay);
 
-            (delay, jitter)
+            Some(BaseDelay::new(delay, max_delay, jitter))
+        } else if let (1, Some(fast_first_retry)) = (n, fast_first_retry) {
+            Some(BaseDelay::new(fast_first_retry, max_delay, jitter))
+        } else if let Some(powi) = n.checked_sub(1) {
+            // `f32::powi()` takes an `i32`, so the exponent saturates for `n > i32::MAX`.
+            // This is intentional: for any `multiplier > 1`, the delay reached `Duration::MAX`
+            // (and so `max_delay`) long before, so the plateau is unaffected.
+            let powi = i32::try_from(powi).unwrap_or(i32::MAX);
+
+            let delay = duration_saturating_mul_f32(initial_delay, multiplier.powi(powi));
+
+            Some(BaseDelay::new(delay, max_delay, jitter))
         } else {
             // We actually _want_ this to evaluate to false if NaN.
             #[allow(clippy::neg_cmp_op_on_partial_ord)]
-            if !(initial_jitter > 0f32) {
-                return Ok(None);
+            if !(initial_jitter > 0f32) && initial_immediate_probability.is_none() {
+                return None;
             }
 
-            let jitter = get_jitter(initial_delay, initial_jitter, rng);
-            (initial_delay, jitter)
-        };
+            Some(BaseDelay::new(initial_delay, max_delay, initial_jitter))
+        }
+    }
 
-        // We only subtract jitter so that `deadline` is a hard limit
-        let retry_at = now + delay - jitter;
+    /// Returns `true` if the delay before every attempt after `n` is the same as for `n`.
+    pub(crate) fn is_steady_from(&self, n: u32) -> bool {
+        if let Some((attempts, steady)) = self.warmup {
+            return n >= attempts && steady.is_steady_from(n);
+        }
 
-        match deadline {
-            Some(deadline) if retry_at > deadline => Err(RetryAfterDeadline {
-                n,
-                retry_at,
-                deadline,
-            }),
-            _ => Ok(Some(retry_at)),
+        let last_override = self.delay_overrides.iter().flatten().map(|&(n, _)| n).max();
+
+        // Attempts `0` and `1` may be special-cased.
+        if n < 2 || last_override.is_some_and(|last| n <= last) {
+            return false;
+        }
+
+        if let Some(schedule) = self.fixed_schedule {
+            return usize::try_from(n).is_ok_and(|n| n >= schedule.len());
+        }
+
+        let delay = self.nth_unjittered_delay(n);
+        let multiplier = self.multiplier;
+
+        // If the multiplier is less than one, the delay will decrease again
+        // once it falls below `max_delay`.
+        n.checked_add(1)
+            .is_some_and(|next| self.nth_unjittered_delay(next) == delay)
+            && (delay.is_zero() || multiplier.is_nan() || multiplier >= 1f32)
+    }
+
+    pub(crate) fn nth_unjittered_delay(&self, n: u32) -> Duration {
+        self.nth_base_delay(n)
+            .map_or(Duration::ZERO, |base| base.delay)
+    }
+}
+
+/// Methods to create an [`EaseOff`], sharing the [`Options`] of this instance.
+///
+/// Equivalent to the methods of the same names on [`Options`].
+/// Useful for storing a single policy to start many controllers from,
+/// which may also back the core API directly (see [`EaseOff::core()`]).
+///
+/// [`Options`] holds no heap allocations, so each `EaseOff` stores its own copy of the core
+/// without allocating, and without the indirection of borrowing or reference-counting it.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use ease_off::core::EaseOffCore;
+/// use ease_off::Options;
+///
+/// static POLICY: EaseOffCore = Options::new()
+///     .initial_delay(Duration::from_millis(50))
+///     .into_core();
+///
+/// let ease_off = POLICY.start_timeout::<std::io::Error>(Duration::from_secs(10));
+/// assert_eq!(ease_off.deadline(), Some(ease_off.started_at() + Duration::from_secs(10)));
+///
+/// // The same policy backs both APIs.
+/// assert_eq!(
+///     ease_off.core().options().get_initial_delay(),
+///     POLICY.options().get_initial_delay(),
+/// );
+/// ```
+impl EaseOffCore {
+    /// See [`Options::start_unlimited()`].
+    pub fn start_unlimited<E>(&self) -> EaseOff<E> {
+        self.clone().start(clock::now(), None)
+    }
+
+    /// See [`Options::start_timeout()`].
+    pub fn start_timeout<E>(&self, timeout: Duration) -> EaseOff<E> {
+        let started_at = clock::now();
+        self.clone()
+            .start(started_at, Some(clock::saturating_add(started_at, timeout)))
+    }
+
+    /// See [`Options::start_timeout_opt()`].
+    pub fn start_timeout_opt<E>(&self, timeout: Option<Duration>) -> EaseOff<E> {
+        let started_at = clock::now();
+        self.clone().start(
+            started_at,
+            timeout.map(|timeout| clock::saturating_add(started_at, timeout)),
+        )
+    }
+
+    /// See [`Options::start_deadline()`].
+    pub fn start_deadline<E>(&self, deadline: Instant) -> EaseOff<E> {
+        self.clone().start(clock::now(), Some(deadline))
+    }
+
+    /// See [`Options::start_deadline_opt()`].
+    pub fn start_deadline_opt<E>(&self, deadline: Option<Instant>) -> EaseOff<E> {
+        self.clone().start(clock::now(), deadline)
+    }
+
+    /// See [`Options::start_timeout_from()`].
+    pub fn start_timeout_from<E>(&self, started_at: Instant, timeout: Duration) -> EaseOff<E> {
+        let started_at = cmp::min(started_at, clock::now());
+        self.clone()
+            .start(started_at, Some(clock::saturating_add(started_at, timeout)))
+    }
+
+    /// See [`Options::start_deadline_from()`].
+    pub fn start_deadline_from<E>(&self, started_at: Instant, deadline: Instant) -> EaseOff<E> {
+        self.clone()
+            .start(cmp::min(started_at, clock::now()), Some(deadline))
+    }
+
+    /// Apply [`Options::deadline_jitter()`] to a deadline.
+    pub(crate) fn jitter_deadline(
+        &self,
+        started_at: Instant,
+        deadline: Instant,
+        rng: &mut (impl Rng + ?Sized),
+    ) -> Instant {
+        let remaining = deadline.saturating_duration_since(started_at);
+        let jitter = self.options.deadline_jitter;
+
+        // Only subtracted so the deadline is never later than requested.
+        deadline - get_jitter(remaining, jitter, rng)
+    }
+
+    pub(crate) fn start<E>(mut self, started_at: Instant, deadline: Option<Instant>) -> EaseOff<E> {
+        // Only acquired if jitter is actually applied.
+        let mut rng = rng::LazyRng::new();
+
+        let spread = self.options.base_spread;
+
+        // `NaN` fails this check
+        if spread > 0f32 {
+            let offset = spread.min(1f32) * rng.gen_range(-1f32..=1f32);
+
+            self.options.initial_delay =
+                duration_saturating_mul_f32(self.options.initial_delay, 1f32 + offset);
+        }
+
+        let deadline =
+            deadline.map(|deadline| self.jitter_deadline(started_at, deadline, &mut rng));
+
+        EaseOff {
+            core: self,
+            started_at,
+            deadline,
+            paused_at: None,
+            paused_for: Duration::ZERO,
+            num_attempts: Saturating(0),
+            attempt_was_delayed: false,
+            total_slept: Duration::ZERO,
+            delay_summary: DelaySummary::default(),
+            history_limit: 0,
+            realized_delays: VecDeque::new(),
+            last_attempt_duration: None,
+            total_attempt_duration: Duration::ZERO,
+            consecutive_timeouts: 0,
+            last_error: None,
+            not_ready_error: None,
+            resumed: false,
+            next_retry_at: None,
+            next_delay: None,
+            make_zero_attempts_error: None,
+            limiter: None,
+            on_failed_attempt: None,
+            fatal_on: None,
+            distinct_errors_by: None,
+            distinct_errors: Vec::new(),
+            recent_errors: None,
+            on_max_delay_reached: None,
+            on_soft_deadline: None,
+            #[cfg(feature = "governor")]
+            governor: None,
+            #[cfg(feature = "log")]
+            log_fields: Default::default(),
+            #[cfg(feature = "futures")]
+            pending_cleanup: None,
+        }
+    }
+}
+
+/// The delay before an attempt, before jitter is applied.
+pub(crate) struct BaseDelay {
+    pub(crate) delay: Duration,
+    /// The jitter factor to apply to `delay`.
+    pub(crate) jitter: f32,
+    pub(crate) clamped_to_max: bool,
+}
+
+impl BaseDelay {
+    fn new(delay: Duration, max_delay: Duration, jitter: f32) -> Self {
+        Self {
+            delay: cmp::min(delay, max_delay),
+            jitter,
+            clamped_to_max: delay >= max_delay,
         }
     }
 }
@@ -129,3 +777,289 @@ fn get_jitter(
 
     duration_saturating_mul_f32(base_duration, jitter_factor)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    /// Run `EaseOff` with the given timeout against a replayed clock, returning the number
+    /// of attempts made and the total delay before them.
+    fn run(core: &EaseOffCore, timeout: Duration) -> (u32, Duration) {
+        let started_at = Instant::now() - Duration::from_secs(3600);
+        let mut ease_off = core.start_deadline_from::<()>(started_at, started_at + timeout);
+
+        let mut attempts = 0;
+
+        loop {
+            let now = started_at + ease_off.delay_summary().total;
+
+            let result = ease_off
+                .try_blocking_at(now, || {
+                    attempts += 1;
+                    Err::<(), _>(())
+                })
+                .or_retry_if(|e| matches!(e, Error::MaybeRetryable(_)));
+
+            if result.is_err() {
+                return (attempts, ease_off.delay_summary().total);
+            }
+        }
+    }
+
+    fn assert_matches_ease_off(options: Options) {
+        let core = options.jitter(0.0).into_core();
+
+        for secs in [0, 1, 2, 3, 5, 6, 7, 10, 15, 16, 30] {
+            let timeout = Duration::from_secs(secs);
+            let (attempts, total) = run(&core, timeout);
+
+            assert_eq!(
+                core.expected_attempts(timeout),
+                attempts,
+                "timeout: {timeout:?}"
+            );
+            assert_eq!(core.max_total_time(attempts), total, "timeout: {timeout:?}");
+        }
+    }
+
+    #[test]
+    fn base_spread_bounds() {
+        let options = Options::new()
+            .initial_delay(Duration::from_millis(100))
+            .base_spread(0.5);
+
+        for _ in 0..1000 {
+            let ease_off = options.start_unlimited::<()>();
+            let initial_delay = ease_off.core().options().get_initial_delay();
+
+            assert!(
+                initial_delay >= Duration::from_millis(49),
+                "{initial_delay:?}"
+            );
+            assert!(
+                initial_delay <= Duration::from_millis(151),
+                "{initial_delay:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn expected_attempts_matches_ease_off() {
+        assert_matches_ease_off(
+            Options::new()
+                .initial_delay(Duration::from_secs(1))
+                .multiplier(2.0)
+                .max_delay(Duration::from_secs(4)),
+        );
+    }
+
+    #[test]
+    fn expected_attempts_matches_ease_off_with_fast_first_retry() {
+        assert_matches_ease_off(
+            Options::new()
+                .initial_delay(Duration::from_secs(2))
+                .fast_first_retry(Duration::from_secs(1))
+                .multiplier(1.0),
+        );
+    }
+
+    #[test]
+    fn expected_attempts_matches_ease_off_with_fixed_schedule() {
+        static SCHEDULE: [Duration; 3] = [
+            Duration::from_secs(3),
+            Duration::from_secs(1),
+            Duration::from_secs(2),
+        ];
+
+        assert_matches_ease_off(Options::new().fixed_schedule(&SCHEDULE));
+    }
+
+    #[test]
+    fn fixed_schedule_through_ease_off() {
+        static SCHEDULE: [Duration; 3] = [
+            Duration::from_millis(3),
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+        ];
+
+        let mut ease_off = Options::new()
+            .jitter(0.0)
+            .fixed_schedule(&SCHEDULE)
+            .start_unlimited()
+            .with_history(10);
+
+        // The first attempt, then five retries.
+        for _ in 0..6 {
+            let _ = ease_off
+                .try_blocking(|| Err::<(), _>("oops"))
+                .or_retry_if(|_e| true);
+        }
+
+        // The first retry was immediate, and the last delay repeats.
+        assert_eq!(
+            *ease_off.realized_delays(),
+            [3, 1, 2, 2].map(Duration::from_millis)
+        );
+    }
+
+    #[test]
+    // We actually _want_ `!(jitter > 0.0)` to be true if NaN.
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn jitter_out_of_range() {
+        let now = Instant::now();
+        let mut rng = rand::thread_rng();
+        let delay = Duration::from_secs(10);
+
+        // (jitter, minimum delay)
+        for (jitter, min) in [
+            (f32::NAN, delay),
+            (f32::NEG_INFINITY, delay),
+            (-1.0, delay),
+            (0.0, delay),
+            (0.5, delay / 2),
+            (1.0, Duration::ZERO),
+            (2.0, Duration::ZERO),
+            (f32::INFINITY, Duration::ZERO),
+        ] {
+            let core = Options::new()
+                .jitter(jitter)
+                .initial_jitter(jitter)
+                .initial_delay(delay)
+                .multiplier(1.0)
+                .into_core();
+
+            for _ in 0..1000 {
+                // Attempt 0 uses `initial_jitter`, attempt 1 uses `jitter`.
+                for n in [0, 1] {
+                    let Some(retry_at) = core.nth_retry_at(n, now, None, &mut rng).unwrap() else {
+                        // No initial jitter; the first attempt is made immediately.
+                        assert!(n == 0 && !(jitter > 0.0), "jitter: {jitter}");
+                        continue;
+                    };
+
+                    let actual = retry_at - now;
+                    assert!(
+                        actual >= min && actual <= delay,
+                        "jitter: {jitter}, delay: {actual:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn quantize_delay_rounds_up_jittered_delay() {
+        let core = Options::new()
+            .initial_delay(Duration::from_millis(150))
+            .jitter(0.25)
+            .quantize_delay(Duration::from_millis(100))
+            .into_core();
+
+        let now = Instant::now();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..1000 {
+            // The first attempt is not delayed, so stays zero.
+            assert_eq!(core.nth_retry_at(0, now, None, &mut rng).unwrap(), None);
+            // `(112.5ms, 150ms]` and `(225ms, 300ms]` after jitter.
+            assert_eq!(
+                core.nth_retry_at(1, now, None, &mut rng).unwrap(),
+                Some(now + Duration::from_millis(200))
+            );
+            assert_eq!(
+                core.nth_retry_at(2, now, None, &mut rng).unwrap(),
+                Some(now + Duration::from_millis(300))
+            );
+        }
+    }
+
+    #[test]
+    fn max_delay_caps_initial_delay() {
+        let now = Instant::now();
+        let mut rng = rand::thread_rng();
+
+        for (jitter, initial_jitter) in [(0.0, 1.0), (0.25, 0.25), (1.0, 0.5)] {
+            let core = Options::new()
+                .initial_delay(Duration::from_secs(10))
+                .max_delay(Duration::from_secs(5))
+                .jitter(jitter)
+                .initial_jitter(initial_jitter)
+                .into_core();
+
+            for n in 0..5 {
+                let retry_at = core.nth_retry_at(n, now, None, &mut rng).unwrap().unwrap();
+                assert!(
+                    retry_at <= now + Duration::from_secs(5),
+                    "jitter: {jitter}, initial_jitter: {initial_jitter}, n: {n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multiplier_exponent_saturates() {
+        let core = Options::new()
+            .jitter(0.0)
+            .max_delay(Duration::from_secs(60))
+            .into_core();
+
+        let now = Instant::now();
+
+        for n in [
+            i32::MAX as u32 - 1,
+            i32::MAX as u32,
+            i32::MAX as u32 + 1,
+            i32::MAX as u32 + 2,
+            u32::MAX,
+        ] {
+            let retry = core
+                .nth_retry_at_detailed(n, now, None, &mut rand::thread_rng())
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(retry.delay, Duration::from_secs(60), "n: {n}");
+            assert!(retry.clamped_to_max, "n: {n}");
+        }
+    }
+
+    #[test]
+    fn max_total_time_of_zero_attempts_is_zero() {
+        let core = Options::new().initial_jitter(0.5).into_core();
+        assert_eq!(core.max_total_time(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn nth_retry_ticks_matches_nth_retry_at() {
+        let core = Options::new()
+            .initial_delay(Duration::from_millis(150))
+            .jitter(0.0)
+            .quantize_delay(Duration::from_secs(1))
+            .initial_immediate_probability(1.0)
+            .initial_jitter(1.0)
+            .into_core();
+
+        let tick = Duration::from_millis(100);
+        let now = Instant::now();
+
+        for n in 0..5 {
+            let delay = core
+                .nth_retry_at_detailed(n, now, None, &mut rand::thread_rng())
+                .unwrap()
+                .map_or(Duration::ZERO, |retry| retry.delay);
+
+            let ticks = core.nth_retry_ticks(n, tick, &mut rand::thread_rng());
+
+            assert_eq!(
+                u128::from(ticks),
+                delay.as_nanos() / tick.as_nanos(),
+                "n: {n}"
+            );
+        }
+
+        // Rounded up to whole seconds by `quantize_delay`, not to 2 ticks.
+        assert_eq!(core.nth_retry_ticks(1, tick, &mut rand::thread_rng()), 10);
+        // Always immediate, despite `initial_jitter`.
+        assert_eq!(core.nth_retry_ticks(0, tick, &mut rand::thread_rng()), 0);
+    }
+}
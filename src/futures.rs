@@ -1,13 +1,21 @@
 //! Backoff support for `async`/`await`.
 
-use crate::{EaseOff, Error, ResultWrapper, TimeoutError};
+use crate::clock;
+use crate::{
+    AttemptContext, EaseOff, Error, OnFailedAttempt, Outcome, PollAfter, ResultWrapper,
+    RetryableError,
+};
 
+use futures_core::Stream;
 use pin_project::pin_project;
+use std::cmp;
+use std::fmt;
 use std::future::{Future, IntoFuture};
 use std::marker::PhantomPinned;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::task::{ready, Context, Poll};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Backoff support for `async`/`await`.
 ///
@@ -50,14 +58,591 @@ impl<E> EaseOff<E> {
         F: FnOnce() -> Fut,
         Fut: Future<Output = Result<T, E>>,
     {
-        TryAsync { ease_off: self, op }
+        TryAsync {
+            ease_off: self,
+            op,
+            sleeper: None,
+            now: None,
+            cancel: None,
+        }
+    }
+
+    /// Like [`Self::try_async()`], but using `now` as the current time for this step
+    /// instead of reading the clock.
+    ///
+    /// The async equivalent of [`EaseOff::try_blocking_at()`]; see that method for details.
+    ///
+    /// `now` is only used to schedule the attempt; the sleep before it waits on the real clock
+    /// unless a sleeper is set with [`TryAsync::with_sleeper()`], which is passed the
+    /// scheduled instant. A deadline enforced with [`TryAsync::enforce_deadline_with()`]
+    /// is measured on the real clock.
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut ease_off = EaseOff::start_unlimited();
+    ///
+    /// let now = Instant::now();
+    /// let scheduled = Arc::new(Mutex::new(vec![]));
+    ///
+    /// for step in 0..3 {
+    ///     let scheduled = scheduled.clone();
+    ///
+    ///     let _ = ease_off
+    ///         .try_async_at(now + Duration::from_secs(step), async { Err::<(), _>("oops") })
+    ///         .with_sleeper(move |instant| {
+    ///             // Record the scheduled time instead of sleeping.
+    ///             scheduled.lock().unwrap().push(instant);
+    ///             std::future::ready(())
+    ///         })
+    ///         .await
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// // The third attempt was scheduled relative to the simulated time of the step.
+    /// let scheduled = scheduled.lock().unwrap();
+    /// assert_eq!(scheduled.len(), 1);
+    /// assert!(scheduled[0] > now + Duration::from_secs(2));
+    /// # }
+    /// ```
+    pub fn try_async_at<T, Fut>(
+        &mut self,
+        now: Instant,
+        op: Fut,
+    ) -> TryAsync<'_, E, impl FnOnce() -> Fut>
+    where
+        Fut: Future<Output = Result<T, E>>,
+    {
+        TryAsync {
+            ease_off: self,
+            op: move || op,
+            sleeper: None,
+            now: Some(now),
+            cancel: None,
+        }
+    }
+
+    /// Attempt an async operation, checking the error for retryability
+    /// using [`RetryableError`].
+    ///
+    /// Equivalent to `self.try_async(op).await.or_retry().into()`, for those who prefer
+    /// to `match` on an [`Outcome`] over the [`ResultWrapper`] combinators.
+    ///
+    /// See the note on this impl block for details.
+    ///
+    /// ### Panics
+    /// If an async runtime is not available for sleeping between retries.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use ease_off::{EaseOff, Outcome, RetryableError};
+    ///
+    /// #[derive(Debug)]
+    /// struct ConnectionReset;
+    ///
+    /// impl RetryableError for ConnectionReset {
+    ///     fn can_retry(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let mut attempts = 0;
+    /// let mut ease_off = EaseOff::start_unlimited();
+    ///
+    /// let value = loop {
+    ///     attempts += 1;
+    ///
+    ///     let op = async {
+    ///         if attempts < 3 {
+    ///             Err(ConnectionReset)
+    ///         } else {
+    ///             Ok("connected")
+    ///         }
+    ///     };
+    ///
+    ///     match ease_off.attempt(op).await {
+    ///         Outcome::Succeeded(value) => break value,
+    ///         Outcome::Retrying => continue,
+    ///         Outcome::GaveUp(e) => panic!("gave up: {e:?}"),
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(value, "connected");
+    /// # }
+    /// ```
+    pub async fn attempt<T, Fut>(&mut self, op: Fut) -> Outcome<T, E>
+    where
+        Fut: Future<Output = Result<T, E>>,
+        E: RetryableError,
+    {
+        self.try_async(op).await.or_retry().into()
+    }
+
+    /// Set an async function to run after each failed attempt, e.g. to reset state or close
+    /// a broken connection before the next attempt.
+    ///
+    /// The function is invoked with the error when it is recorded for retrying,
+    /// as described for [`EaseOff::on_failed_attempt()`], and the returned future
+    /// is awaited at the start of the next async attempt, before the backoff sleep.
+    /// The time spent in the future does not count towards the backoff delay.
+    ///
+    /// Blocking attempts (e.g. [`EaseOff::try_blocking()`]) do not await the future;
+    /// it remains pending until the next async attempt.
+    ///
+    /// Replaces any function previously set by this method or its blocking equivalent.
+    pub fn on_failed_attempt_async<F, Fut>(mut self, mut on_failed_attempt: F) -> Self
+    where
+        F: FnMut(&E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.on_failed_attempt = Some(OnFailedAttempt::Async(Box::new(move |e| {
+            Cleanup(Box::pin(on_failed_attempt(e)))
+        })));
+        self
+    }
+
+    /// Attempt an async operation which may not be ready yet.
+    ///
+    /// The async equivalent of [`EaseOff::try_blocking_poll()`]; see that method for details.
+    ///
+    /// The operation is immediately cancelled without being polled
+    /// if the deadline has already elapsed. Otherwise, it is run to completion.
+    ///
+    /// See the note on this impl block for details.
+    ///
+    /// ### Panics
+    /// If an async runtime is not available for sleeping between retries.
+    pub async fn try_async_poll<T, Fut>(
+        &mut self,
+        op: Fut,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E>
+    where
+        Fut: Future<Output = Result<Option<T>, E>>,
+    {
+        self.attempt_async(|_| op, |this, res| this.wrap_poll_result(res, make_error))
+            .await
+    }
+
+    /// Retry the async operation returned by `op` until it succeeds or a terminal condition
+    /// is reached, checking errors for retryability using [`RetryableError`].
+    ///
+    /// Unlike [`Self::try_async()`], which makes a single attempt, this drives the whole loop:
+    /// it is equivalent to calling `self.try_async_with(&mut op).await.or_retry()`
+    /// until it returns `Ok(Some(_))` or `Err(_)`.
+    ///
+    /// `op` is called to create a new future for every attempt.
+    ///
+    /// ### Errors
+    /// The loop ends with `Err` as soon as any of the following occurs; in every case,
+    /// the error is that of the most recent attempt (or produced by
+    /// [`EaseOff::on_zero_attempts()`] if none was made):
+    /// * The error is not retryable ([`RetryableError::can_retry()`] returns `false`,
+    ///   or it was made fatal by [`EaseOff::fatal_on()`]).
+    /// * The [deadline][EaseOff::deadline()] elapses before the next attempt.
+    /// * A [`RetryLimiter`][crate::RetryLimiter] denies the retry.
+    /// * A limit such as [`Options::max_consecutive_timeouts()`][crate::Options::max_consecutive_timeouts()]
+    ///   is reached.
+    ///
+    /// With [`EaseOff::start_unlimited()`] and an error that is always retryable,
+    /// the loop only ends on success.
+    ///
+    /// ### Panics
+    /// If an async runtime is not available for sleeping between retries.
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    /// use ease_off::{EaseOff, Options, RetryableError};
+    ///
+    /// #[derive(Debug)]
+    /// struct Flaky;
+    ///
+    /// impl RetryableError for Flaky {
+    ///     fn can_retry(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .start_timeout(Duration::from_secs(10));
+    ///
+    /// let mut attempts = 0;
+    ///
+    /// let value = ease_off
+    ///     .retry(|| {
+    ///         attempts += 1;
+    ///         let attempt = attempts;
+    ///
+    ///         async move {
+    ///             if attempt < 3 {
+    ///                 Err(Flaky)
+    ///             } else {
+    ///                 Ok(attempt)
+    ///             }
+    ///         }
+    ///     })
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value, 3);
+    /// # }
+    /// ```
+    pub async fn retry<T, F, Fut>(&mut self, mut op: F) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: RetryableError,
+    {
+        loop {
+            if let Some(value) = self.try_async_with(&mut op).await.or_retry()? {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Attempt an async operation, passing an [`AttemptContext`] describing the current
+    /// attempt to the closure which creates it.
+    ///
+    /// The async equivalent of [`EaseOff::try_blocking_ctx()`]; see that method for details.
+    ///
+    /// The closure is not called if the deadline has already elapsed.
+    /// Otherwise, the operation is run to completion.
+    ///
+    /// See the note on this impl block for details.
+    ///
+    /// ### Panics
+    /// If an async runtime is not available for sleeping between retries.
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .start_timeout(Duration::from_secs(10));
+    ///
+    /// let mut timeouts = vec![];
+    ///
+    /// for _ in 0..3 {
+    ///     let _ = ease_off
+    ///         .try_async_ctx(|ctx| {
+    ///             // Widen the per-attempt timeout on later attempts.
+    ///             timeouts.push(Duration::from_millis(100) * ctx.attempt_number);
+    ///             async { Err::<(), _>("oops") }
+    ///         })
+    ///         .await
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// assert_eq!(timeouts, [100, 200, 300].map(Duration::from_millis));
+    /// # }
+    /// ```
+    pub async fn try_async_ctx<T, F, Fut>(&mut self, op: F) -> ResultWrapper<'_, T, E>
+    where
+        F: FnOnce(AttemptContext) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        self.attempt_async(|this| op(this.attempt_context()), EaseOff::wrap_result)
+            .await
+    }
+
+    /// Attempt an async operation which may not be ready yet,
+    /// and which says how long to wait before checking again.
+    ///
+    /// The async equivalent of [`EaseOff::try_blocking_poll_after()`];
+    /// see that method for details.
+    ///
+    /// The operation is immediately cancelled without being polled
+    /// if the deadline has already elapsed. Otherwise, it is run to completion.
+    ///
+    /// See the note on this impl block for details.
+    ///
+    /// ### Panics
+    /// If an async runtime is not available for sleeping between retries.
+    pub async fn try_async_poll_after<T, Fut>(
+        &mut self,
+        op: Fut,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E>
+    where
+        Fut: Future<Output = Result<PollAfter<T>, E>>,
+    {
+        self.attempt_async(
+            |_| op,
+            |this, res| this.wrap_poll_after_result(res, make_error),
+        )
+        .await
+    }
+
+    /// Attempt to receive the next item from a fallible [`Stream`].
+    ///
+    /// If the previous item was an error, sleeps for the prescribed backoff period before
+    /// polling the stream again. The backoff is reset after each successful item,
+    /// so every transient failure starts from the beginning of the schedule.
+    ///
+    /// The successful value is `Some(item)`, or `None` if the stream has ended.
+    /// A terminal error (fatal, or the deadline elapsing) is returned from `.or_retry()`
+    /// as `Err` like any other operation; it is up to the caller to stop polling the stream
+    /// at that point.
+    ///
+    /// Note that the stream must be able to yield more items after yielding an error
+    /// for retrying to make sense.
+    ///
+    /// See the note on this impl block for details on deadline behavior.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> Result<(), String> {
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut stream = futures::stream::iter([Ok(1), Err("transient".to_string()), Ok(2)]);
+    /// let mut ease_off = EaseOff::start_unlimited();
+    ///
+    /// let mut items = vec![];
+    ///
+    /// loop {
+    ///     let Some(item) = ease_off
+    ///         .try_stream_item(&mut stream)
+    ///         .await
+    ///         .or_retry_if(|_e| true)?
+    ///     else {
+    ///         continue;
+    ///     };
+    ///
+    ///     let Some(item) = item else {
+    ///         // End of stream
+    ///         break;
+    ///     };
+    ///
+    ///     items.push(item);
+    /// }
+    ///
+    /// assert_eq!(items, [1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_stream_item<'s, T, S>(
+        &mut self,
+        stream: &'s mut S,
+    ) -> TryAsync<'_, E, impl FnOnce() -> StreamItem<'s, S>>
+    where
+        S: Stream<Item = Result<T, E>> + Unpin + ?Sized,
+    {
+        self.try_async_with(move || StreamItem { stream })
+    }
+
+    /// The async equivalent of `EaseOff::attempt_blocking()`: await any pending cleanup,
+    /// sleep until the next attempt is due, then make it by awaiting the future
+    /// returned by `op`.
+    ///
+    /// The result, or the error if no attempt is made, is passed to `wrap`.
+    async fn attempt_async<R, T, Fut>(
+        &mut self,
+        op: impl FnOnce(&Self) -> Fut,
+        wrap: impl FnOnce(&mut Self, Result<R, Error<E>>) -> ResultWrapper<'_, T, E>,
+    ) -> ResultWrapper<'_, T, E>
+    where
+        Fut: Future<Output = Result<R, E>>,
+    {
+        std::future::poll_fn(|cx| self.poll_cleanup(cx)).await;
+
+        let res = match self.next_retry_at_from(clock::now()) {
+            Ok(retry_at) => {
+                if let Some(retry_at) = retry_at {
+                    Sleep::until(retry_at).await;
+                }
+
+                #[cfg(feature = "governor")]
+                loop {
+                    match self.governor_retry_at(clock::now()) {
+                        Ok(Some(retry_at)) => Sleep::until(retry_at).await,
+                        Ok(None) => break,
+                        Err(e) => return wrap(self, Err(e)),
+                    }
+                }
+
+                let fut = op(self);
+                let started_at = clock::now();
+                let res = fut.await.map_err(Error::MaybeRetryable);
+                self.record_attempt_duration(started_at);
+                res
+            }
+            Err(e) => Err(e),
+        };
+
+        wrap(self, res)
+    }
+
+    /// Poll the cleanup returned by an [`on_failed_attempt_async`] function, if any.
+    ///
+    /// The cleanup is only cleared once it completes, so if the caller is dropped first,
+    /// it resumes before the next attempt instead of being lost.
+    ///
+    /// [`on_failed_attempt_async`]: Self::on_failed_attempt_async()
+    fn poll_cleanup(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if let Some(cleanup) = &mut self.pending_cleanup {
+            ready!(Pin::new(cleanup).poll(cx));
+            self.pending_cleanup = None;
+        }
+
+        Poll::Ready(())
+    }
+}
+
+/// The most recent progress of an async operation, preserved if the operation is cancelled.
+///
+/// When [`TryAsync::enforce_deadline_with()`] or [`TryAsync::timeout_after()`] cancels
+/// an operation, the future is dropped and any work it has completed would be lost.
+/// Instead, the operation may record its progress in a `Checkpoint` shared with
+/// the closure producing the timeout error, which can then take the last checkpoint
+/// and include it in the error.
+///
+/// Cloning a `Checkpoint` returns a handle to the same value.
+///
+/// ### Cancellation Safety
+/// A future can only be cancelled at an `.await` point, so the value is whatever
+/// was last [set][Self::set] before the `.await` at which the operation was cancelled.
+/// To make use of it:
+///
+/// * Set a checkpoint only once a step is complete, so it never refers to half-done work.
+/// * Make each checkpoint self-contained (e.g. everything completed so far, not just the
+///   latest step), as earlier values are replaced.
+/// * Checkpoints are not cleared between attempts; [take][Self::take] or
+///   [clear][Self::clear] it at the start of an attempt if it should not carry over.
+///
+/// The lock is never held across an `.await`, so it is safe to use from any runtime.
+///
+/// ```rust
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use std::time::Duration;
+/// use ease_off::futures::Checkpoint;
+/// use ease_off::EaseOff;
+///
+/// #[derive(Debug)]
+/// struct Timeout {
+///     completed: Vec<u32>,
+/// }
+///
+/// let checkpoint = Checkpoint::new();
+/// let mut ease_off = EaseOff::start_timeout(Duration::from_millis(50));
+///
+/// let result = ease_off
+///     .try_async({
+///         let checkpoint = checkpoint.clone();
+///
+///         async move {
+///             let mut completed = vec![];
+///
+///             for step in 0.. {
+///                 completed.push(step);
+///                 checkpoint.set(completed.clone());
+///
+///                 tokio::time::sleep(Duration::from_millis(10)).await;
+///             }
+///
+///             Ok::<_, Timeout>(completed)
+///         }
+///     })
+///     .enforce_deadline_with(|_e| Timeout {
+///         completed: checkpoint.take().unwrap_or_default(),
+///     })
+///     .await
+///     .or_retry_if(|_e| false);
+///
+/// let completed = result.unwrap_err().completed;
+/// assert!(!completed.is_empty());
+/// assert_eq!(completed[0], 0);
+/// # }
+/// ```
+pub struct Checkpoint<C>(Arc<Mutex<Option<C>>>);
+
+impl<C> Checkpoint<C> {
+    /// Create an empty checkpoint.
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(None)))
+    }
+
+    /// Record `value`, replacing the previous checkpoint if any.
+    pub fn set(&self, value: C) {
+        *self.lock() = Some(value);
+    }
+
+    /// Take the most recent checkpoint, leaving it empty.
+    pub fn take(&self) -> Option<C> {
+        self.lock().take()
+    }
+
+    /// Clear the checkpoint.
+    pub fn clear(&self) {
+        *self.lock() = None;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<C>> {
+        // The value is only ever replaced, so it cannot be left in an inconsistent state.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<C> Clone for Checkpoint<C> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<C> Default for Checkpoint<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: fmt::Debug> fmt::Debug for Checkpoint<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Checkpoint").field(&*self.lock()).finish()
+    }
+}
+
+/// The error produced by [`TryAsync::timeout_after()`] if the attempt timed out
+/// and no previous attempt failed.
+///
+/// Converts into [`std::io::Error`] with [`ErrorKind::TimedOut`][std::io::ErrorKind::TimedOut];
+/// for other error types, implement `From<Elapsed>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("attempt timed out")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+impl From<Elapsed> for std::io::Error {
+    fn from(elapsed: Elapsed) -> Self {
+        std::io::Error::new(std::io::ErrorKind::TimedOut, elapsed)
     }
 }
 
 /// `.await`able type returned by [`EaseOff::try_async()`] and [`EaseOff::try_async_with()`].
 ///
 /// ### Panics
-/// If an async runtime is not available for sleeping between retries.
+/// If an async runtime is not available for sleeping between retries
+/// and no sleeper was set with [`Self::with_sleeper()`].
 ///
 /// ### Note: Behavior at Deadline
 /// Unless otherwise stated, async operations are _not_ cancelled at the [deadline][EaseOff::deadline()]
@@ -73,6 +658,10 @@ impl<E> EaseOff<E> {
 pub struct TryAsync<'a, E, F> {
     ease_off: &'a mut EaseOff<E>,
     op: F,
+    sleeper: Option<Sleeper>,
+    // Set by `EaseOff::try_async_at()`.
+    now: Option<Instant>,
+    cancel: Option<Cancel<'a, E>>,
 }
 
 /// [`Future`] returned by [`TryAsync::into_future()`], [`TryAsync::enforce_deadline_with()`].
@@ -81,17 +670,194 @@ pub struct TryAsync<'a, E, F> {
 /// the future will not be invoked immediately.
 ///
 /// ### Panics
-/// If an async runtime is not available for sleeping between retries.
+/// If an async runtime is not available for sleeping between retries
+/// and no sleeper was set with [`TryAsync::with_sleeper()`].
+///
+/// ### Cancel Safety
+/// This future may be dropped before it completes, e.g. when it loses a `select!`.
+/// The [`EaseOff`] is only borrowed, not moved, so it can always be used again afterward,
+/// and is left in a consistent state depending on when the future was dropped:
+///
+/// * Before it was first polled: the `EaseOff` is unchanged.
+/// * While awaiting the cleanup returned by an [`on_failed_attempt_async`] function:
+///   the cleanup is kept, and resumes before the next attempt.
+/// * During the backoff sleep, or while the operation is in progress: the attempt counts
+///   toward [`EaseOff::num_attempts()`], so the next attempt waits for the next backoff period
+///   as if the dropped attempt had failed. The error from the previous failed attempt is kept.
+///
+/// In the last case, the scheduled sleep is included in the
+/// [delay statistics][EaseOff::delay_summary()] even if it did not complete,
+/// and [`EaseOff::last_attempt_duration()`] is not updated.
+///
+/// The same applies to the futures returned by [`TryAsync::enforce_deadline_with()`]
+/// and the other methods of [`TryAsync`], and by [`EaseOff::try_async_ctx()`],
+/// [`EaseOff::try_async_poll()`] and [`EaseOff::try_async_poll_after()`].
+///
+/// ```rust
+/// use std::future::{pending, IntoFuture};
+/// use std::time::Duration;
+/// use futures::FutureExt;
+/// use ease_off::Options;
+///
+/// let mut ease_off = Options::new()
+///     .initial_delay(Duration::from_millis(1))
+///     .start_timeout(Duration::from_secs(60));
+///
+/// futures::executor::block_on(async {
+///     // Dropped before it is polled.
+///     drop(ease_off.try_async(async { Err::<(), _>("unreachable") }).into_future());
+///     assert_eq!(ease_off.num_attempts(), 0);
+///
+///     // Dropped during the first attempt, which is then made again.
+///     let dropped = ease_off
+///         .try_async(pending::<Result<(), &str>>())
+///         .into_future()
+///         .now_or_never();
+///     assert!(dropped.is_none());
+///     assert_eq!(ease_off.num_attempts(), 0);
+///
+///     let result = ease_off
+///         .try_async(async { Err::<(), _>("oops") })
+///         .await
+///         .or_retry_if(|_e| true);
+///     assert_eq!(result, Ok(None));
+///
+///     // Dropped during a retry. The first retry is immediate, so no sleep was needed.
+///     let dropped = ease_off
+///         .try_async(pending::<Result<(), &str>>())
+///         .into_future()
+///         .now_or_never();
+///     assert!(dropped.is_none());
+///     assert_eq!(ease_off.num_attempts(), 1);
+///
+///     // Dropped during the backoff sleep before the next retry.
+///     let dropped = ease_off
+///         .try_async(async { Err::<(), _>("unreachable") })
+///         .with_sleeper(|_| pending())
+///         .into_future()
+///         .now_or_never();
+///     assert!(dropped.is_none());
+///     assert_eq!(ease_off.num_attempts(), 2);
+///
+///     // The previous error is still there, and the instance can be used as normal.
+///     assert!(ease_off.next_retry_at_peek().is_ok());
+///
+///     let result = ease_off
+///         .try_async(async { Ok::<_, &str>("hello, world!") })
+///         .with_sleeper(|_| async {})
+///         .await
+///         .or_retry_if(|_e| true);
+///     assert_eq!(result, Ok(Some("hello, world!")));
+///     assert_eq!(ease_off.num_attempts(), 3);
+/// });
+/// ```
+///
+/// [`on_failed_attempt_async`]: EaseOff::on_failed_attempt_async()
 #[pin_project]
 pub struct TryAsyncFuture<'a, E, F, Fut> {
     // Wrapped in `Option` so we can take and subsequently return ownership in `poll()`
     ease_off: Option<&'a mut EaseOff<E>>,
+    sleeper: Option<Sleeper>,
+    now: Option<Instant>,
+    // Dropped once the backoff sleep completes, as the operation itself is not cancelled.
+    cancel: Option<Cancel<'a, E>>,
+    // Set when the operation is first polled, for `EaseOff::last_attempt_duration()`.
+    op_started_at: Option<Instant>,
     #[pin]
     op: LazyOp<F, Fut>,
     #[pin]
     sleep: Sleep,
 }
 
+/// An async runtime which may be used to sleep between retries.
+///
+/// Which variants exist depends on the enabled features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Runtime {
+    /// Tokio; requires the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    Tokio,
+    /// `async-io` version 2, which also backs `async-std`; requires the `async-io-2` feature.
+    #[cfg(feature = "async-io-2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async-io-2")))]
+    AsyncIo2,
+}
+
+impl Runtime {
+    /// Returns the runtime that will be used to sleep between retries in the current context.
+    ///
+    /// If multiple runtime features are enabled, the runtime is chosen in the following order:
+    ///
+    /// 1. [`Tokio`][Self::Tokio], if called from within the context of a Tokio runtime.
+    /// 2. [`AsyncIo2`][Self::AsyncIo2] (which also covers `async-std`), which does not
+    ///    require a runtime context as it spawns its own driver thread if necessary.
+    ///
+    /// Returns `None` if none of the enabled runtimes are available,
+    /// in which case any attempt to sleep will panic.
+    ///
+    /// ```rust
+    /// use ease_off::futures::Runtime;
+    ///
+    /// # #[cfg(feature = "tokio")]
+    /// tokio::runtime::Builder::new_current_thread()
+    ///     .enable_time()
+    ///     .build()
+    ///     .unwrap()
+    ///     .block_on(async {
+    ///         // Tokio takes precedence, even if `async-io-2` is also enabled.
+    ///         assert_eq!(Runtime::current(), Some(Runtime::Tokio));
+    ///     });
+    ///
+    /// // Outside of a Tokio runtime.
+    /// # #[cfg(feature = "async-io-2")]
+    /// assert_eq!(Runtime::current(), Some(Runtime::AsyncIo2));
+    /// # #[cfg(not(feature = "async-io-2"))]
+    /// assert_eq!(Runtime::current(), None);
+    /// ```
+    pub fn current() -> Option<Self> {
+        #[cfg(feature = "tokio")]
+        if tokio::runtime::Handle::try_current().is_ok() {
+            return Some(Self::Tokio);
+        }
+
+        #[cfg(feature = "async-io-2")]
+        return Some(Self::AsyncIo2);
+
+        #[allow(unreachable_code)]
+        None
+    }
+}
+
+/// [`Future`] which receives the next item from a fallible [`Stream`].
+///
+/// Used by [`EaseOff::try_stream_item()`].
+#[must_use = "futures do nothing unless `.await`ed or polled"]
+pub struct StreamItem<'s, S: ?Sized> {
+    stream: &'s mut S,
+}
+
+/// Future returned by the function passed to [`EaseOff::on_failed_attempt_async()`].
+pub(crate) struct Cleanup(Pin<Box<dyn Future<Output = ()> + Send>>);
+
+impl Future for Cleanup {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+/// Function passed to [`TryAsync::with_sleeper()`].
+type Sleeper = Box<dyn Fn(Instant) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
+impl fmt::Debug for Cleanup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Cleanup(..)")
+    }
+}
+
 #[pin_project(project = LazyOpPinned)]
 enum LazyOp<F, Fut> {
     NotStarted(Option<F>),
@@ -109,12 +875,21 @@ enum Sleep {
     Tokio(#[pin] tokio::time::Sleep),
     #[cfg(feature = "async-io-2")]
     AsyncIo2(async_io_2::Timer),
+    Custom(#[pin] Pin<Box<dyn Future<Output = ()> + Send>>),
+}
+
+// Only constructed by `TryAsync::with_cancellation_token()`.
+#[cfg_attr(not(feature = "tokio-util"), allow(dead_code))]
+struct Cancel<'a, E> {
+    cancelled: Pin<Box<dyn Future<Output = ()> + Send + 'a>>,
+    make_error: Box<dyn FnOnce(Option<E>) -> E + Send + 'a>,
 }
 
+// `sleep` may be any future; its output is ignored.
 #[pin_project]
-struct Timeout<Fut> {
+struct Timeout<S, Fut> {
     #[pin]
-    sleep: Sleep,
+    sleep: S,
     #[pin]
     future: Fut,
 }
@@ -130,6 +905,10 @@ where
     fn into_future(self) -> Self::IntoFuture {
         TryAsyncFuture {
             ease_off: Some(self.ease_off),
+            sleeper: self.sleeper,
+            now: self.now,
+            cancel: self.cancel,
+            op_started_at: None,
             sleep: Sleep::Unset,
             op: LazyOp::NotStarted(Some(self.op)),
         }
@@ -141,12 +920,80 @@ where
     F: FnOnce() -> Fut,
     Fut: Future<Output = Result<T, E>>,
 {
+    /// Sleep using the future returned by the given function instead of the built-in
+    /// [`Runtime`] support.
+    ///
+    /// The function is called with the [`Instant`] to sleep until, and the returned future
+    /// should complete once that instant has been reached.
+    /// This allows the use of any runtime, or virtual time in tests.
+    ///
+    /// If set, this takes precedence over [`Runtime::current()`], so no runtime feature
+    /// needs to be enabled. It is used for the backoff sleep before the attempt,
+    /// and again for the timeout if [`Self::enforce_deadline_with()`]
+    /// or [`Self::timeout_after()`] is used.
+    /// If no sleep is needed, the function is not called.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let sleeps = Arc::new(Mutex::new(Vec::new()));
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(60));
+    ///
+    /// let value = futures::executor::block_on(async {
+    ///     let mut attempts = 0;
+    ///
+    ///     loop {
+    ///         let sleeps = sleeps.clone();
+    ///
+    ///         let res = ease_off
+    ///             .try_async(async {
+    ///                 attempts += 1;
+    ///                 if attempts < 3 { Err("not yet") } else { Ok(attempts) }
+    ///             })
+    ///             .with_sleeper(move |instant| {
+    ///                 // Pretend we slept without actually waiting.
+    ///                 sleeps.lock().unwrap().push(instant);
+    ///                 std::future::ready(())
+    ///             })
+    ///             .await
+    ///             .or_retry_if(|_e| true)?;
+    ///
+    ///         if let Some(value) = res {
+    ///             break Ok::<_, &str>(value);
+    ///         }
+    ///     }
+    /// })
+    /// .unwrap();
+    ///
+    /// assert_eq!(value, 3);
+    /// // The first retry is immediate, so only the second retry slept.
+    /// assert_eq!(sleeps.lock().unwrap().len(), 1);
+    /// ```
+    pub fn with_sleeper<S, SFut>(mut self, sleeper: S) -> Self
+    where
+        S: Fn(Instant) -> SFut + Send + 'static,
+        SFut: Future<Output = ()> + Send + 'static,
+    {
+        self.sleeper = Some(Box::new(move |instant| Box::pin(sleeper(instant))));
+        self
+    }
+
     // TODO: design an API that automatically creates an `E` for convenience/reusability
     /// Cancel the operation as soon as the [deadline][EaseOff::deadline()] elapses, if set.
     ///
     /// The closure will be called to produce the error that will be returned;
     /// if the operation failed on a previous attempt, that error is included.
     ///
+    /// Like awaiting the [`TryAsync`] directly, this first sleeps for the backoff delay
+    /// and counts the attempt towards [`EaseOff::num_attempts()`].
+    ///
+    /// To salvage the progress of a cancelled operation, have it record a [`Checkpoint`]
+    /// and take it in the closure.
+    ///
     /// ### Panics
     /// If an async runtime is not available for managing the timeout.
     ///
@@ -174,24 +1021,329 @@ where
         self,
         make_error: impl FnOnce(Option<E>) -> E,
     ) -> ResultWrapper<'a, T, E> {
+        self.enforce_timeout(None, make_error).await
+    }
+
+    /// Cancel the operation if it does not complete within `timeout`,
+    /// or when the [deadline][EaseOff::deadline()] elapses, whichever is sooner.
+    ///
+    /// This is a per-call timeout, independent of the deadline of the [`EaseOff`],
+    /// and is equivalent to [`Self::enforce_deadline_with()`] with a deadline of
+    /// `Instant::now() + timeout` when the operation starts (after any backoff sleep).
+    ///
+    /// If the operation is cancelled, [`Error::TimedOut`] is returned with the error
+    /// from the previous attempt, if it failed, or else [`Elapsed`] converted into `E`.
+    /// To produce the error yourself, use [`Self::timeout_after_with()`].
+    ///
+    /// [`Error::TimedOut`] is never retried by `.or_retry()`, so to retry an attempt
+    /// that timed out (while the deadline of the `EaseOff` has not elapsed),
+    /// convert it with [`ResultWrapper::on_timeout()`].
+    ///
+    /// ### Panics
+    /// If an async runtime is not available for managing the timeout.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use std::io;
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(60));
+    ///
+    /// let result = ease_off
+    ///     // An async operation that will never complete.
+    ///     .try_async(std::future::pending::<io::Result<()>>())
+    ///     .timeout_after(Duration::from_millis(10))
+    ///     .await
+    ///     .or_retry_if(|_e| false);
+    ///
+    /// assert_eq!(result.unwrap_err().kind(), io::ErrorKind::TimedOut);
+    /// # }
+    /// ```
+    pub async fn timeout_after(self, timeout: Duration) -> ResultWrapper<'a, T, E>
+    where
+        E: From<Elapsed>,
+    {
+        self.timeout_after_with(timeout, |e| e.unwrap_or_else(|| Elapsed(()).into()))
+            .await
+    }
+
+    /// Like [`Self::timeout_after()`], but the closure is called to produce the error
+    /// that will be returned as [`Error::TimedOut`]; if the operation failed on a previous
+    /// attempt, that error is included.
+    ///
+    /// ### Panics
+    /// If an async runtime is not available for managing the timeout.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(60));
+    ///
+    /// let result = ease_off
+    ///     // An async operation that will never complete.
+    ///     .try_async(std::future::pending::<Result<String, String>>())
+    ///     .timeout_after_with(Duration::from_millis(10), |_e| "attempt timed out".to_string())
+    ///     .await
+    ///     .or_retry_if(|_e| false);
+    ///
+    /// assert_eq!(result.unwrap_err(), "attempt timed out");
+    /// # }
+    /// ```
+    pub async fn timeout_after_with(
+        self,
+        timeout: Duration,
+        make_error: impl FnOnce(Option<E>) -> E,
+    ) -> ResultWrapper<'a, T, E> {
+        self.enforce_timeout(Some(timeout), make_error).await
+    }
+
+    /// Cancel the operation as soon as `deadline` completes, using a timer the caller owns.
+    ///
+    /// This is the most runtime-agnostic way to enforce a deadline: `deadline` may be any
+    /// future, e.g. a sleep from whichever runtime is in use, or a signal from elsewhere
+    /// in the application. Its output is ignored. The operation is raced against it,
+    /// and if `deadline` completes first, the operation is dropped and the error produced by
+    /// the closure is returned as [`Error::TimedOut`], as for [`Self::enforce_deadline_with()`].
+    /// If the operation failed on a previous attempt, that error is passed to the closure.
+    ///
+    /// The caller owns the timer: `deadline` is not polled until the operation starts,
+    /// after any backoff sleep, and is dropped once the attempt completes.
+    /// It replaces, rather than adds to, the [deadline][EaseOff::deadline()]
+    /// of the [`EaseOff`] for cancelling this attempt; that deadline still applies
+    /// when scheduling the next attempt.
+    ///
+    /// The backoff sleep before the attempt still uses the sleeper set by
+    /// [`Self::with_sleeper()`], or the current [`Runtime`], if a sleep is needed.
+    ///
+    /// ### Panics
+    /// If a backoff sleep is needed, an async runtime is not available for it,
+    /// and no sleeper was set with [`Self::with_sleeper()`].
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{EaseOff, Error};
+    /// use futures::channel::oneshot;
+    ///
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(60));
+    ///
+    /// // Stands in for a timer from any runtime.
+    /// let (fire, deadline) = oneshot::channel::<()>();
+    ///
+    /// let result = futures::executor::block_on(async {
+    ///     ease_off
+    ///         .try_async(async {
+    ///             // Something happens which means this attempt should be abandoned.
+    ///             fire.send(()).unwrap();
+    ///             std::future::pending::<Result<(), &str>>().await
+    ///         })
+    ///         .enforce_deadline_future(deadline, |_e| "deadline elapsed")
+    ///         .await
+    ///         .inspect_err(|e| assert!(matches!(e, Error::TimedOut(_))))
+    ///         .or_retry_if(|e| e.is_maybe_retryable())
+    /// });
+    ///
+    /// assert_eq!(result, Err("deadline elapsed"));
+    /// ```
+    pub async fn enforce_deadline_future(
+        self,
+        deadline: impl Future,
+        make_error: impl FnOnce(Option<E>) -> E,
+    ) -> ResultWrapper<'a, T, E> {
+        self.race_op(|_, _, _| deadline, make_error).await
+    }
+
+    /// Stop retrying once `token` is cancelled, e.g. for graceful shutdown.
+    ///
+    /// If the token is cancelled before the operation starts, including during the backoff
+    /// sleep, the sleep is cut short, the operation is not run, and [`Error::Cancelled`]
+    /// is returned. Once the operation has started, it is allowed to run to completion;
+    /// to abort it, race it against [`CancellationToken::cancelled()`] yourself.
+    ///
+    /// The closure will be called to produce the error that will be returned;
+    /// if the operation failed on a previous attempt, that error is included.
+    ///
+    /// Also applies with [`Self::enforce_deadline_with()`], [`Self::timeout_after()`]
+    /// and [`Self::enforce_deadline_future()`].
+    ///
+    /// A `CancellationToken` does not depend on the Tokio runtime, so this only requires
+    /// the `tokio-util` feature; sleeping still requires a runtime feature or
+    /// [`Self::with_sleeper()`].
+    ///
+    /// [`CancellationToken::cancelled()`]: tokio_util::sync::CancellationToken::cancelled()
+    ///
+    /// ### Example
+    ///
+    #[cfg_attr(feature = "tokio", doc = "```rust")]
+    #[cfg_attr(
+        not(feature = "tokio"),
+        doc = "```rust,ignore\n\
+               // Note: example not compiled if `tokio` feature is not enabled.\n"
+    )]
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use std::time::Duration;
+    /// use ease_off::{EaseOff, Error, Options};
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// let token = CancellationToken::new();
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_secs(60))
+    ///     .start_timeout(Duration::from_secs(600));
+    ///
+    /// // Shut down while the retry loop is sleeping.
+    /// tokio::spawn({
+    ///     let token = token.clone();
+    ///     async move {
+    ///         tokio::time::sleep(Duration::from_millis(10)).await;
+    ///         token.cancel();
+    ///     }
+    /// });
+    ///
+    /// let mut attempts = 0;
+    /// let mut cancelled = false;
+    ///
+    /// let result = loop {
+    ///     let res = ease_off
+    ///         .try_async(async {
+    ///             attempts += 1;
+    ///             Err::<(), _>("service unavailable")
+    ///         })
+    ///         .with_cancellation_token(token.clone(), |e| e.unwrap_or("cancelled"))
+    ///         .await
+    ///         .inspect_err(|e| cancelled = matches!(e, Error::Cancelled(_)))
+    ///         .or_retry_if(|e| matches!(e, Error::MaybeRetryable(_)));
+    ///
+    ///     match res {
+    ///         Ok(Some(value)) => break Ok(value),
+    ///         Ok(None) => continue,
+    ///         Err(e) => break Err(e),
+    ///     }
+    /// };
+    ///
+    /// // The first retry is immediate; the token was cancelled during the second backoff sleep.
+    /// assert_eq!(attempts, 2);
+    /// assert_eq!(result, Err("service unavailable"));
+    /// assert!(cancelled);
+    /// # }
+    /// ```
+    #[cfg(feature = "tokio-util")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio-util")))]
+    pub fn with_cancellation_token(
+        mut self,
+        token: tokio_util::sync::CancellationToken,
+        make_error: impl FnOnce(Option<E>) -> E + Send + 'a,
+    ) -> Self {
+        self.cancel = Some(Cancel {
+            cancelled: Box::pin(token.cancelled_owned()),
+            make_error: Box::new(make_error),
+        });
+        self
+    }
+
+    async fn enforce_timeout(
+        self,
+        timeout: Option<Duration>,
+        make_error: impl FnOnce(Option<E>) -> E,
+    ) -> ResultWrapper<'a, T, E> {
+        self.race_op(
+            |ease_off, now, sleeper| {
+                let deadline = match (ease_off.deadline_at(now), timeout) {
+                    (Some(deadline), Some(timeout)) => {
+                        Some(cmp::min(deadline, clock::saturating_add(now, timeout)))
+                    }
+                    (deadline, timeout) => deadline
+                        .or_else(|| timeout.map(|timeout| clock::saturating_add(now, timeout))),
+                };
+
+                deadline.map_or(Sleep::Forever(PhantomPinned), |deadline| {
+                    Sleep::until_with(deadline, sleeper)
+                })
+            },
+            make_error,
+        )
+        .await
+    }
+
+    /// Sleep for the backoff, then race the operation against the future returned by
+    /// `make_deadline`, returning [`Error::TimedOut`] if the latter completes first.
+    async fn race_op<D: Future>(
+        self,
+        make_deadline: impl FnOnce(&EaseOff<E>, Instant, Option<&Sleeper>) -> D,
+        make_error: impl FnOnce(Option<E>) -> E,
+    ) -> ResultWrapper<'a, T, E> {
+        let TryAsync {
+            ease_off,
+            op,
+            sleeper,
+            now,
+            cancel,
+        } = self;
+
+        std::future::poll_fn(|cx| ease_off.poll_cleanup(cx)).await;
+
+        let backoff = match ease_off.next_retry_at_from(now.unwrap_or_else(clock::now)) {
+            Ok(Some(retry_at)) => Sleep::until_with(retry_at, sleeper.as_ref()),
+            Ok(None) => Sleep::Skipped,
+            Err(e) => return ease_off.wrap_result(Err(e)),
+        };
+
+        match cancel {
+            Some(cancel) => {
+                // `Ok` means the token was cancelled before the backoff sleep completed.
+                let cancelled = Timeout {
+                    sleep: backoff,
+                    future: cancel.cancelled,
+                }
+                .await
+                .is_ok();
+
+                if cancelled {
+                    let e = (cancel.make_error)(ease_off.last_error.take());
+                    return ease_off.wrap_result(Err(Error::Cancelled(e)));
+                }
+            }
+            None => backoff.await,
+        }
+
+        #[cfg(feature = "governor")]
+        loop {
+            match ease_off.governor_retry_at(clock::now()) {
+                Ok(Some(retry_at)) => Sleep::until_with(retry_at, sleeper.as_ref()).await,
+                Ok(None) => break,
+                Err(e) => return ease_off.wrap_result(Err(e)),
+            }
+        }
+
+        let now = clock::now();
+
         let res = Timeout {
-            sleep: self
-                .ease_off
-                .deadline
-                .map_or(Sleep::Forever(PhantomPinned), Sleep::until),
-            future: (self.op)(),
+            sleep: make_deadline(ease_off, now, sleeper.as_ref()),
+            future: op(),
         }
         .await
         .map_or_else(
             |_| {
-                Err(Error::TimedOut(TimeoutError {
-                    last_error: make_error(self.ease_off.last_error.take()),
-                }))
+                let last_error = make_error(ease_off.last_error.take());
+                Err(ease_off.timed_out(last_error))
             },
             |res| res.map_err(Error::MaybeRetryable),
         );
 
-        self.ease_off.wrap_result(res)
+        ease_off.record_attempt_duration(now);
+
+        ease_off.wrap_result(res)
     }
 }
 
@@ -205,15 +1357,31 @@ where
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let mut this = self.project();
 
+        if let Some(cancel) = this.cancel {
+            if cancel.cancelled.as_mut().poll(cx).is_ready() {
+                let cancel = this.cancel.take().expect("BUG: this.cancel already taken");
+                let ease_off = this
+                    .ease_off
+                    .take()
+                    .expect("BUG: this.ease_off already taken");
+
+                let e = (cancel.make_error)(ease_off.last_error.take());
+                return Poll::Ready(ease_off.wrap_result(Err(Error::Cancelled(e))));
+            }
+        }
+
         if this.sleep.is_unset() {
             let ease_off = this
                 .ease_off
                 .as_deref_mut()
                 .expect("BUG: this.ease_off already taken");
 
-            match ease_off.next_retry_at() {
+            ready!(ease_off.poll_cleanup(cx));
+
+            match ease_off.next_retry_at_from(this.now.unwrap_or_else(clock::now)) {
                 Ok(Some(retry_at)) => {
-                    this.sleep.set(Sleep::until(retry_at));
+                    this.sleep
+                        .set(Sleep::until_with(retry_at, this.sleeper.as_ref()));
                 }
                 Ok(None) => {
                     this.sleep.set(Sleep::Skipped);
@@ -231,14 +1399,45 @@ where
 
         ready!(this.sleep.as_mut().poll(cx));
 
+        // Checked again after every sleep it asks for, until the operation is started.
+        #[cfg(feature = "governor")]
+        while this.op_started_at.is_none() {
+            let ease_off = this
+                .ease_off
+                .as_deref_mut()
+                .expect("BUG: this.ease_off already taken");
+
+            match ease_off.governor_retry_at(clock::now()) {
+                Ok(Some(retry_at)) => {
+                    this.sleep
+                        .set(Sleep::until_with(retry_at, this.sleeper.as_ref()));
+                    ready!(this.sleep.as_mut().poll(cx));
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    return Poll::Ready(
+                        this.ease_off
+                            .take()
+                            .expect("BUG: this.ease_off already taken")
+                            .wrap_result(Err(e)),
+                    );
+                }
+            }
+        }
+
+        *this.cancel = None;
+
+        let started_at = *this.op_started_at.get_or_insert_with(clock::now);
         let res = ready!(this.op.poll(cx)).map_err(Error::MaybeRetryable);
 
-        Poll::Ready(
-            this.ease_off
-                .take()
-                .expect("BUG: this.ease_off already taken")
-                .wrap_result(res),
-        )
+        let ease_off = this
+            .ease_off
+            .take()
+            .expect("BUG: this.ease_off already taken");
+
+        ease_off.record_attempt_duration(started_at);
+
+        Poll::Ready(ease_off.wrap_result(res))
     }
 }
 
@@ -264,23 +1463,41 @@ where
     }
 }
 
+impl<T, E, S> Future for StreamItem<'_, S>
+where
+    S: Stream<Item = Result<T, E>> + Unpin + ?Sized,
+{
+    type Output = Result<Option<T>, E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream)
+            .poll_next(cx)
+            .map(Option::transpose)
+    }
+}
+
 impl Sleep {
+    // `instant` is unused if no runtime feature is enabled.
+    #[cfg_attr(
+        not(any(feature = "tokio", feature = "async-io-2")),
+        allow(unused_variables)
+    )]
     fn until(instant: Instant) -> Self {
-        #[cfg(feature = "tokio")]
-        if tokio::runtime::Handle::try_current().is_ok() {
-            return Self::Tokio(tokio::time::sleep_until(instant.into()));
-        }
-
-        #[cfg(feature = "async-io-2")]
-        {
-            Self::AsyncIo2(async_io_2::Timer::at(instant))
+        match Runtime::current() {
+            #[cfg(feature = "tokio")]
+            Some(Runtime::Tokio) => Self::Tokio(tokio::time::sleep_until(instant.into())),
+            #[cfg(feature = "async-io-2")]
+            Some(Runtime::AsyncIo2) => Self::AsyncIo2(async_io_2::Timer::at(instant)),
+            None if cfg!(feature = "tokio") => panic!("no Tokio runtime available"),
+            None => panic!("no async runtime enabled"),
         }
+    }
 
-        #[cfg(not(feature = "async-io-2"))]
-        if cfg!(feature = "tokio") {
-            panic!("no Tokio runtime available")
-        } else {
-            panic!("no async runtime enabled")
+    /// Like [`Self::until()`], but uses `sleeper` if set.
+    fn until_with(instant: Instant, sleeper: Option<&Sleeper>) -> Self {
+        match sleeper {
+            Some(sleeper) => Self::Custom(sleeper(instant)),
+            None => Self::until(instant),
         }
     }
 
@@ -300,11 +1517,12 @@ impl Future for Sleep {
             SleepPinned::Tokio(sleep) => sleep.poll(cx),
             #[cfg(feature = "async-io-2")]
             SleepPinned::AsyncIo2(sleep) => Pin::new(sleep).poll(cx).map(|_| ()),
+            SleepPinned::Custom(sleep) => sleep.poll(cx),
         }
     }
 }
 
-impl<Fut: Future> Future for Timeout<Fut> {
+impl<S: Future, Fut: Future> Future for Timeout<S, Fut> {
     type Output = Result<Fut::Output, ()>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -314,10 +1532,188 @@ impl<Fut: Future> Future for Timeout<Fut> {
             return Poll::Ready(Ok(ready));
         }
 
-        if let Poll::Ready(()) = this.sleep.poll(cx) {
+        if this.sleep.poll(cx).is_ready() {
             return Poll::Ready(Err(()));
         }
 
         Poll::Pending
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::futures::FutureExt;
+    use std::future::{pending, poll_fn, ready};
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+    /// Cleanup for `on_failed_attempt_async()` which does not complete until opened.
+    #[derive(Clone, Default)]
+    struct Gate(Arc<(AtomicBool, AtomicU32)>);
+
+    impl Gate {
+        fn wait(&self) -> impl Future<Output = ()> + Send + 'static {
+            let gate = self.clone();
+
+            poll_fn(move |_cx| {
+                let (open, completed) = &*gate.0;
+
+                if !open.load(Ordering::SeqCst) {
+                    return Poll::Pending;
+                }
+
+                completed.fetch_add(1, Ordering::SeqCst);
+                Poll::Ready(())
+            })
+        }
+
+        fn open(&self) {
+            self.0 .0.store(true, Ordering::SeqCst);
+        }
+
+        fn completed(&self) -> u32 {
+            self.0 .1.load(Ordering::SeqCst)
+        }
+    }
+
+    /// Fail once with a cleanup that waits on a [`Gate`], drop `$attempt` while it is awaiting
+    /// the cleanup, then check that making `$attempt` again resumes the same cleanup.
+    macro_rules! assert_resumes_cleanup {
+        (|$ease_off:ident| $attempt:expr) => {{
+            let gate = Gate::default();
+
+            let mut $ease_off = EaseOff::start_unlimited().on_failed_attempt_async({
+                let gate = gate.clone();
+                move |_e: &&str| gate.wait()
+            });
+
+            let result = $ease_off
+                .try_async(async { Err::<(), _>("oops") })
+                .into_future()
+                .now_or_never()
+                .unwrap()
+                .or_retry_if(|_e| true);
+            assert_eq!(result, Ok(None));
+
+            // Dropped while awaiting the cleanup.
+            assert!($attempt.now_or_never().is_none());
+            assert_eq!(gate.completed(), 0);
+            assert_eq!($ease_off.num_attempts(), 0);
+
+            gate.open();
+
+            assert!($attempt.now_or_never().is_some());
+            assert_eq!(gate.completed(), 1);
+        }};
+    }
+
+    #[test]
+    fn try_async_resumes_cleanup() {
+        assert_resumes_cleanup!(|ease_off| ease_off
+            .try_async(async { Ok::<_, &str>(()) })
+            .into_future());
+    }
+
+    #[test]
+    fn enforce_deadline_with_resumes_cleanup() {
+        assert_resumes_cleanup!(|ease_off| ease_off
+            .try_async(async { Ok::<_, &str>(()) })
+            .enforce_deadline_with(|e| e.unwrap_or("timed out")));
+    }
+
+    #[test]
+    fn try_async_ctx_resumes_cleanup() {
+        assert_resumes_cleanup!(
+            |ease_off| ease_off.try_async_ctx(|_ctx| async { Ok::<_, &str>(()) })
+        );
+    }
+
+    #[test]
+    fn try_async_poll_resumes_cleanup() {
+        assert_resumes_cleanup!(
+            |ease_off| ease_off.try_async_poll(async { Ok::<_, &str>(Some(())) }, || "not ready")
+        );
+    }
+
+    #[test]
+    fn try_async_poll_after_resumes_cleanup() {
+        assert_resumes_cleanup!(|ease_off| ease_off
+            .try_async_poll_after(async { Ok::<_, &str>(PollAfter::Ready(())) }, || {
+                "not ready"
+            }));
+    }
+
+    /// Returns a sleeper which completes immediately, and the instants it was called with.
+    fn recording_sleeper() -> (
+        Arc<Mutex<Vec<Instant>>>,
+        impl Fn(Instant) -> std::future::Ready<()> + Send + 'static,
+    ) {
+        let sleeps = Arc::new(Mutex::new(Vec::new()));
+
+        let sleeper = {
+            let sleeps = sleeps.clone();
+            move |instant| {
+                sleeps.lock().unwrap().push(instant);
+                ready(())
+            }
+        };
+
+        (sleeps, sleeper)
+    }
+
+    #[test]
+    fn timeout_after_uses_sleeper_for_backoff_and_timeout() {
+        let mut ease_off = crate::Options::new()
+            .initial_delay(Duration::from_millis(1))
+            .start_timeout(Duration::from_secs(60));
+
+        futures::executor::block_on(async {
+            // The first attempt and the first retry are not delayed.
+            for _ in 0..2 {
+                let (sleeps, sleeper) = recording_sleeper();
+
+                let result = ease_off
+                    .try_async(async { Err::<(), _>(std::io::Error::other("oops")) })
+                    .with_sleeper(sleeper)
+                    .await
+                    .or_retry_if(|_e| true);
+
+                assert!(matches!(result, Ok(None)));
+                assert!(sleeps.lock().unwrap().is_empty());
+            }
+
+            let (sleeps, sleeper) = recording_sleeper();
+
+            let result = ease_off
+                .try_async(pending::<std::io::Result<()>>())
+                .with_sleeper(sleeper)
+                .timeout_after(Duration::from_secs(1))
+                .await
+                .or_retry_if(|e| e.is_maybe_retryable());
+
+            // The previous error is returned.
+            assert_eq!(result.unwrap_err().to_string(), "oops");
+            // Once for the backoff and once for the timeout, without falling back to a runtime.
+            assert_eq!(sleeps.lock().unwrap().len(), 2);
+        });
+    }
+
+    #[test]
+    fn timeout_after_without_previous_error_returns_elapsed() {
+        let mut ease_off = EaseOff::start_timeout(Duration::from_secs(60));
+        let (sleeps, sleeper) = recording_sleeper();
+
+        let result = futures::executor::block_on(
+            ease_off
+                .try_async(pending::<std::io::Result<()>>())
+                .with_sleeper(sleeper)
+                .timeout_after(Duration::from_secs(1)),
+        )
+        .or_retry_if(|e| e.is_maybe_retryable());
+
+        let e = result.unwrap_err();
+        assert_eq!(e.kind(), std::io::ErrorKind::TimedOut);
+        assert_eq!(e.to_string(), "attempt timed out");
+        assert_eq!(sleeps.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,214 @@
+//! Helpers for retrying HTTP requests.
+//!
+//! This module does not depend on any particular HTTP client;
+//! it only deals in status codes and header values.
+//!
+//! ```rust
+//! use std::ops::ControlFlow;
+//! use std::time::Duration;
+//! use ease_off::http::HttpError;
+//! use ease_off::{EaseOff, RetryableError};
+//!
+//! // Pretend responses from a server.
+//! let mut responses = vec![
+//!     Ok("hello, world!"),
+//!     Err(HttpError::new(503).retry_after_header("0")),
+//!     Err(HttpError::new(502)),
+//! ];
+//!
+//! let mut ease_off = EaseOff::start_timeout(Duration::from_secs(30));
+//!
+//! let body = loop {
+//!     let Some(body) = ease_off
+//!         .try_blocking(|| responses.pop().unwrap())
+//!         .or_retry_with(|e| {
+//!             if e.can_retry() {
+//!                 // Honor `Retry-After` if the server sent it.
+//!                 ControlFlow::Continue(e.inner().retry_at)
+//!             } else {
+//!                 ControlFlow::Break(())
+//!             }
+//!         })?
+//!     else {
+//!         continue;
+//!     };
+//!
+//!     break body;
+//! };
+//!
+//! assert_eq!(body, "hello, world!");
+//! # Ok::<_, HttpError>(())
+//! ```
+
+use crate::{clock, RetryableError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The status codes considered retryable by [`is_retryable_status()`].
+///
+/// | Status | Meaning               |
+/// |--------|-----------------------|
+/// | 408    | Request Timeout       |
+/// | 429    | Too Many Requests     |
+/// | 500    | Internal Server Error |
+/// | 502    | Bad Gateway           |
+/// | 503    | Service Unavailable   |
+/// | 504    | Gateway Timeout       |
+pub const RETRYABLE_STATUSES: &[u16] = &[408, 429, 500, 502, 503, 504];
+
+/// Returns `true` if `status` is one of [`RETRYABLE_STATUSES`].
+///
+/// All other statuses, including other `4xx` and `5xx` codes, are considered fatal.
+pub fn is_retryable_status(status: u16) -> bool {
+    RETRYABLE_STATUSES.contains(&status)
+}
+
+/// An error response from an HTTP server, implementing [`RetryableError`] based on its status.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("HTTP status {status}")]
+#[non_exhaustive]
+pub struct HttpError {
+    /// The status code of the response.
+    pub status: u16,
+    /// The time to retry at, if the response specified it.
+    ///
+    /// See [`Self::retry_after_header()`].
+    pub retry_at: Option<Instant>,
+    /// The status codes considered retryable; [`RETRYABLE_STATUSES`] by default.
+    ///
+    /// See [`Self::retryable_statuses()`].
+    pub retryable_statuses: &'static [u16],
+}
+
+impl HttpError {
+    /// Create an error for a response with the given status code.
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            retry_at: None,
+            retryable_statuses: RETRYABLE_STATUSES,
+        }
+    }
+
+    /// Override the status codes considered retryable for this error.
+    ///
+    /// ```rust
+    /// use ease_off::http::{HttpError, RETRYABLE_STATUSES};
+    /// use ease_off::RetryableError;
+    ///
+    /// // This API returns `409 Conflict` for transient lock contention.
+    /// static STATUSES: &[u16] = &[409, 429, 503];
+    ///
+    /// assert!(HttpError::new(409).retryable_statuses(STATUSES).can_retry());
+    /// assert!(!HttpError::new(409).can_retry());
+    /// ```
+    pub fn retryable_statuses(self, retryable_statuses: &'static [u16]) -> Self {
+        Self {
+            retryable_statuses,
+            ..self
+        }
+    }
+
+    /// Set [`Self::retry_at`] from the value of a `Retry-After` header.
+    ///
+    /// Both forms of the header are supported: a number of seconds (e.g. `120`),
+    /// or an HTTP date in the preferred format (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`).
+    /// The obsolete RFC 850 and `asctime()` date formats are not supported.
+    ///
+    /// If the value cannot be parsed, [`Self::retry_at`] is left unchanged.
+    /// A date in the past results in a retry time of now.
+    pub fn retry_after_header(self, value: &str) -> Self {
+        Self {
+            retry_at: parse_retry_after(value).or(self.retry_at),
+            ..self
+        }
+    }
+}
+
+impl RetryableError for HttpError {
+    fn can_retry(&self) -> bool {
+        self.retryable_statuses.contains(&self.status)
+    }
+}
+
+/// Parse the value of a `Retry-After` header into an [`Instant`].
+///
+/// See [`HttpError::retry_after_header()`] for the supported formats.
+pub fn parse_retry_after(value: &str) -> Option<Instant> {
+    let value = value.trim();
+
+    let delay = match value.parse::<u64>() {
+        Ok(secs) => Duration::from_secs(secs),
+        Err(_) => {
+            let date = parse_imf_fixdate(value)?;
+
+            date.duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO)
+        }
+    };
+
+    Some(clock::saturating_add(clock::now(), delay))
+}
+
+/// Parse a date like `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn parse_imf_fixdate(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let mut parts = value.split(' ');
+
+    let (_weekday, day, month, year, time, "GMT", None) = (
+        parts.next()?.strip_suffix(',')?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next(),
+    ) else {
+        return None;
+    };
+
+    let day: u64 = parse_digits(day, 2)?;
+    let month = MONTHS.iter().position(|&m| m == month)? as u64 + 1;
+    let year: u64 = parse_digits(year, 4)?;
+
+    let mut time = time.split(':');
+
+    let (hour, minute, second, None) = (
+        parse_digits(time.next()?, 2)?,
+        parse_digits(time.next()?, 2)?,
+        parse_digits(time.next()?, 2)?,
+        time.next(),
+    ) else {
+        return None;
+    };
+
+    if !(1..=31).contains(&day) || year < 1970 || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    // Days since the epoch from a civil date; see http://howardhinnant.github.io/date_algorithms.html
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = y / 400;
+    let yoe = y % 400;
+    let doy = (153 * m + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = (era * 146_097 + doe).checked_sub(719_468)?;
+
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second;
+
+    UNIX_EPOCH.checked_add(Duration::from_secs(secs))
+}
+
+fn parse_digits(s: &str, len: usize) -> Option<u64> {
+    if s.len() != len || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
@@ -0,0 +1,118 @@
+use crate::{EaseOff, RetryableError};
+
+/// Retry each item of a fallible [`Iterator`], sleeping between retries.
+///
+/// The returned iterator yields each successful item as `Ok(_)`.
+/// If the underlying iterator yields a retryable error (per [`RetryableError::can_retry()`]),
+/// it sleeps for the backoff period using [`std::thread::sleep()`], then polls it again.
+///
+/// If an error is fatal, or the [deadline][EaseOff::deadline()] elapses while retrying,
+/// the error is yielded as `Err(_)` and the returned iterator ends.
+/// It also ends when the underlying iterator does.
+///
+/// Note that the underlying iterator must be able to yield more items after yielding an error
+/// for retrying to make sense.
+///
+/// ### Schedule and Deadline
+/// The iterator is driven by `ease_off`, so the same rules apply as when calling
+/// [`EaseOff::try_blocking()`] in a loop:
+///
+/// * After a successful item, the schedule starts over for the next item.
+/// * The deadline, if set, applies across the whole iteration, not to each item.
+///   Since the first attempt is always made, an item that succeeds without retrying is yielded
+///   even after the deadline has elapsed; the deadline only limits retries.
+///   Use [`EaseOff::start_unlimited()`] or a generous timeout for long iterations.
+///
+/// ### Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use ease_off::{EaseOff, RetryableError};
+///
+/// #[derive(Debug)]
+/// enum ReadError {
+///     Transient,
+///     Corrupt,
+/// }
+///
+/// impl RetryableError for ReadError {
+///     fn can_retry(&self) -> bool {
+///         matches!(self, Self::Transient)
+///     }
+/// }
+///
+/// let records = [
+///     Ok(1),
+///     Err(ReadError::Transient),
+///     Ok(2),
+///     Err(ReadError::Corrupt),
+///     Ok(3),
+/// ];
+///
+/// let ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+/// let mut iter = ease_off::retry_iter(ease_off, records);
+///
+/// assert_eq!(iter.next().unwrap().unwrap(), 1);
+/// assert_eq!(iter.next().unwrap().unwrap(), 2);
+/// assert!(matches!(iter.next(), Some(Err(ReadError::Corrupt))));
+/// assert!(iter.next().is_none());
+/// ```
+pub fn retry_iter<I, T, E>(ease_off: EaseOff<E>, iter: I) -> RetryIter<I::IntoIter, E>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+    E: RetryableError,
+{
+    RetryIter {
+        ease_off: Some(ease_off),
+        iter: iter.into_iter(),
+    }
+}
+
+/// [`Iterator`] returned by [`retry_iter()`].
+#[derive(Debug)]
+pub struct RetryIter<I, E> {
+    // `None` once an error has been yielded.
+    ease_off: Option<EaseOff<E>>,
+    iter: I,
+}
+
+impl<I, E> RetryIter<I, E> {
+    /// Returns the `EaseOff` driving this iterator,
+    /// or `None` if it has ended with an error.
+    pub fn ease_off(&self) -> Option<&EaseOff<E>> {
+        self.ease_off.as_ref()
+    }
+
+    /// Unwrap the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, T, E> Iterator for RetryIter<I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    E: RetryableError,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ease_off = self.ease_off.as_mut()?;
+
+        loop {
+            let result = ease_off
+                .try_blocking(|| self.iter.next().transpose())
+                .or_retry();
+
+            match result {
+                // `Some(None)` is the end of the underlying iterator.
+                Ok(Some(item)) => return item.map(Ok),
+                Ok(None) => continue,
+                Err(e) => {
+                    self.ease_off = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
@@ -33,10 +33,14 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(missing_docs)]
 
-use crate::core::EaseOffCore;
+use crate::core::{EaseOffCore, RetryAfterDeadline};
 use std::cmp;
+use std::collections::VecDeque;
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::num::Saturating;
 use std::ops::ControlFlow;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 #[cfg(feature = "futures")]
@@ -45,9 +49,47 @@ pub mod futures;
 
 pub mod core;
 
+#[cfg(feature = "code")]
+#[cfg_attr(docsrs, doc(cfg(feature = "code")))]
+pub mod code;
+
+#[cfg(feature = "http")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+pub mod http;
+
+#[cfg(feature = "reqwest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+pub mod reqwest;
+
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
+mod clock;
+#[cfg(feature = "serde")]
+mod config;
+mod iter;
+mod limiter;
+mod macros;
 mod options;
+mod rng;
+#[cfg(feature = "spec")]
+mod spec;
+mod state;
 
+pub use clock::now;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use config::{DelayOverrideConfig, OptionsConfig};
+pub use iter::{retry_iter, RetryIter};
+pub use limiter::RetryLimiter;
+#[cfg(any(feature = "serde", feature = "spec"))]
+pub use options::InvalidOptions;
 pub use options::Options;
+#[cfg(feature = "spec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spec")))]
+pub use spec::ParseSpecError;
+pub use state::EaseOffState;
 
 /// Exponential backoff controller.
 ///
@@ -57,9 +99,75 @@ pub struct EaseOff<E> {
     core: EaseOffCore,
     started_at: Instant,
     deadline: Option<Instant>,
+    paused_at: Option<Instant>,
+    paused_for: Duration,
     num_attempts: Saturating<u32>,
+    attempt_was_delayed: bool,
+    total_slept: Duration,
+    delay_summary: DelaySummary,
+    // See `Self::with_history()`; disabled if zero. Capped by `Options::history_capacity()`.
+    history_limit: usize,
+    // Oldest first.
+    realized_delays: VecDeque<Duration>,
+    last_attempt_duration: Option<Duration>,
+    total_attempt_duration: Duration,
+    consecutive_timeouts: u32,
     last_error: Option<E>,
+    // Set after a not-ready result if no attempt has failed, to be returned instead of
+    // `last_error` if the deadline elapses. Cleared once an attempt fails.
+    not_ready_error: Option<E>,
+    // Set by `Options::resume()`, so the restored schedule is not reset before the next attempt.
+    resumed: bool,
     next_retry_at: Option<Instant>,
+    // Set by `PollAfter::Pending`, overriding the calculated delay.
+    next_delay: Option<Duration>,
+    make_zero_attempts_error: Option<fn() -> E>,
+    limiter: Option<Arc<RetryLimiter>>,
+    on_failed_attempt: Option<OnFailedAttempt<E>>,
+    fatal_on: Option<FatalOn<E>>,
+    distinct_errors_by: Option<ErrorKey<E>>,
+    // Hashes of the keys returned by `distinct_errors_by`; see `Options::max_distinct_errors()`.
+    distinct_errors: Vec<u64>,
+    recent_errors: Option<RecentErrors<E>>,
+    on_max_delay_reached: Option<OnMaxDelayReached>,
+    on_soft_deadline: Option<OnSoftDeadline>,
+    #[cfg(feature = "governor")]
+    governor: Option<GovernorCheck>,
+    #[cfg(feature = "log")]
+    log_fields: LogFields,
+    #[cfg(feature = "futures")]
+    pending_cleanup: Option<futures::Cleanup>,
+}
+
+struct FatalOn<E>(Box<dyn Fn(&E) -> bool + Send + Sync>);
+
+struct ErrorKey<E>(Box<dyn Fn(&E) -> u64 + Send + Sync>);
+
+// See `EaseOff::keep_recent_errors()`.
+struct RecentErrors<E> {
+    limit: usize,
+    to_string: fn(&E) -> String,
+    // Oldest first; does not include `EaseOff::last_error`.
+    errors: VecDeque<String>,
+}
+
+struct OnMaxDelayReached(Box<dyn FnOnce() + Send + Sync>);
+
+struct OnSoftDeadline(Box<dyn FnOnce() + Send + Sync>);
+
+// See `EaseOff::with_fields()`.
+#[cfg(feature = "log")]
+#[derive(Debug, Default)]
+struct LogFields(Vec<(&'static str, String)>);
+
+// Returns the earliest time the limiter allows an attempt, or `None` if it allows one now.
+#[cfg(feature = "governor")]
+struct GovernorCheck(Box<dyn Fn(Instant) -> Option<Instant> + Send + Sync>);
+
+enum OnFailedAttempt<E> {
+    Blocking(Box<dyn FnMut(&E) + Send + Sync>),
+    #[cfg(feature = "futures")]
+    Async(Box<dyn FnMut(&E) -> futures::Cleanup + Send + Sync>),
 }
 
 impl<E> EaseOff<E> {
@@ -69,6 +177,49 @@ impl<E> EaseOff<E> {
         Options::DEFAULT.start_unlimited()
     }
 
+    /// Begin backing off with **indefinite** retries, waiting at most `max_delay`
+    /// between attempts.
+    ///
+    /// This is a common policy for background tasks that should never give up,
+    /// such as reconnecting to a server. Equivalent to:
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use ease_off::{EaseOff, Options};
+    /// # let max_delay = Duration::from_secs(30);
+    /// # let _: EaseOff<()> =
+    /// Options::DEFAULT.max_delay(max_delay).start_unlimited()
+    /// # ;
+    /// ```
+    ///
+    /// The delay grows exponentially until it reaches `max_delay`, then stays there
+    /// (minus [jitter][Options::jitter]). The operation is retried until it succeeds
+    /// or a non-retryable error occurs.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let max_delay = Duration::from_millis(1);
+    /// let mut ease_off = EaseOff::start_unlimited_capped(max_delay);
+    ///
+    /// assert_eq!(ease_off.deadline(), None);
+    ///
+    /// for _ in 0..50 {
+    ///     let res = ease_off
+    ///         .try_blocking(|| Err::<(), _>("still down"))
+    ///         .or_retry_if(|_e| true);
+    ///
+    ///     // Never gives up on its own.
+    ///     assert_eq!(res, Ok(None));
+    ///     assert!(ease_off.summary().next_delay <= Some(max_delay));
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn start_unlimited_capped(max_delay: Duration) -> Self {
+        Options::DEFAULT.max_delay(max_delay).start_unlimited()
+    }
+
     /// Alias for [`Options::start_timeout()`] using [`Options::DEFAULT`].
     #[inline(always)]
     pub fn start_timeout(timeout: Duration) -> Self {
@@ -93,6 +244,47 @@ impl<E> EaseOff<E> {
         Options::DEFAULT.start_deadline_opt(deadline)
     }
 
+    /// Returns the [`EaseOffCore`] calculating the backoff for this instance.
+    ///
+    /// This is also available via [`AsRef`], so code computing schedules with the core API
+    /// can accept either an `EaseOff` or an `EaseOffCore`.
+    #[inline(always)]
+    pub fn core(&self) -> &EaseOffCore {
+        &self.core
+    }
+
+    /// Set the [jitter][Options::jitter()] factor for this instance only.
+    ///
+    /// Useful for tuning a call site (e.g. more jitter for a known-hot endpoint)
+    /// while sharing the rest of a common set of [`Options`].
+    ///
+    /// Each `EaseOff` holds its own copy of the `Options` it was started with,
+    /// so this modifies that copy; the shared `Options` or [`EaseOffCore`] are unaffected.
+    /// Takes effect from the next attempt.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// static OPTIONS: Options = Options::new().jitter(0.25);
+    ///
+    /// let mut ease_off = OPTIONS.start_timeout::<()>(Duration::from_secs(30));
+    /// ease_off.set_jitter(0.75);
+    ///
+    /// assert_eq!(ease_off.core().options().get_jitter(), 0.75);
+    /// assert_eq!(OPTIONS.get_jitter(), 0.25);
+    /// ```
+    pub fn set_jitter(&mut self, jitter: f32) {
+        self.core = EaseOffCore::new(self.core.options().clone().jitter(jitter));
+    }
+
+    /// Set the [initial jitter][Options::initial_jitter()] factor for this instance only.
+    ///
+    /// See [`Self::set_jitter()`] for details.
+    pub fn set_initial_jitter(&mut self, initial_jitter: f32) {
+        self.core = EaseOffCore::new(self.core.options().clone().initial_jitter(initial_jitter));
+    }
+
     /// Returns the [`Instant`] when this instance was constructed.
     #[inline(always)]
     pub fn started_at(&self) -> Instant {
@@ -103,253 +295,2884 @@ impl<E> EaseOff<E> {
     ///
     /// If constructed with a timeout, it is converted to a deadline on construction
     /// by adding the timeout to [`Self::started_at()`].
+    ///
+    /// The deadline is pushed back by the time spent [paused][Self::pause()] when resumed.
     #[inline(always)]
     pub fn deadline(&self) -> Option<Instant> {
         self.deadline
     }
 
-    /// Returns the number of attempts that have been made.
+    /// Set the deadline to the earlier of the current deadline and `deadline`.
     ///
-    /// Saturates at [`u32::MAX`].
-    #[inline(always)]
-    pub fn num_attempts(&self) -> u32 {
-        self.num_attempts.0
+    /// This never extends the deadline, so a backoff loop always respects the most restrictive
+    /// deadline, e.g. when a deadline propagated with a request tightens as the request
+    /// passes through layers of an application. If no deadline is set, `deadline` is used.
+    ///
+    /// Like any other deadline, it is pushed back by the time spent [paused][Self::pause()].
+    /// With [`Options::deadline_excludes_sleep()`], it is also pushed back by all time spent
+    /// sleeping between attempts, including any sleeps before this call.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut ease_off = EaseOff::<()>::start_timeout(Duration::from_secs(30));
+    /// let deadline = ease_off.deadline().unwrap();
+    ///
+    /// // Tightened...
+    /// ease_off.tighten_deadline(deadline - Duration::from_secs(10));
+    /// assert_eq!(ease_off.deadline(), Some(deadline - Duration::from_secs(10)));
+    ///
+    /// // ...but never extended.
+    /// ease_off.tighten_deadline(deadline + Duration::from_secs(10));
+    /// assert_eq!(ease_off.deadline(), Some(deadline - Duration::from_secs(10)));
+    ///
+    /// let mut unlimited = EaseOff::<()>::start_unlimited();
+    /// unlimited.tighten_deadline(deadline);
+    /// assert_eq!(unlimited.deadline(), Some(deadline));
+    /// ```
+    pub fn tighten_deadline(&mut self, deadline: Instant) {
+        self.deadline = Some(match self.deadline {
+            Some(current) => cmp::min(current, deadline),
+            None => deadline,
+        });
     }
 
-    fn next_retry_at(&mut self) -> Result<Option<Instant>, Error<E>> {
-        let now = Instant::now();
-
-        let mut rng = rand::thread_rng();
+    /// Pause the clock of this instance, so that the deadline is not consumed while paused.
+    ///
+    /// Useful when retries are known to be pointless for a while, e.g. when the application
+    /// is suspended or a dependency is known to be down, so that a long pause does not
+    /// cause the deadline to elapse immediately once resumed.
+    ///
+    /// While paused, the deadline is treated as if [`Self::resume()`] was called
+    /// at the time of the check. Attempts are not prevented while paused.
+    ///
+    /// Does nothing if already paused.
+    ///
+    /// ### Note: In-Progress Attempts
+    /// Because this method takes `&mut self`, it cannot be called while an attempt
+    /// is in progress. Sleeps and deadline timeouts are calculated when the attempt starts,
+    /// so pausing has no effect on an attempt that has already started.
+    pub fn pause(&mut self) {
+        self.paused_at.get_or_insert_with(clock::now);
+    }
 
-        if self.last_error.is_none() {
-            self.num_attempts = Saturating(0);
-            return Ok(cmp::max(
-                self.core
-                    .nth_retry_at(0, now, None, &mut rng)
-                    .expect("passed `None` for deadline, should not be `Err`"),
-                self.next_retry_at.take(),
-            ));
-        }
+    /// Resume the clock of this instance after a call to [`Self::pause()`].
+    ///
+    /// The deadline, if set, is pushed back by the time spent paused.
+    ///
+    /// Does nothing if not paused.
+    pub fn resume(&mut self) {
+        let Some(paused_at) = self.paused_at.take() else {
+            return;
+        };
 
-        let attempt_num = self.num_attempts.0;
-        // `num_attempts` is `Saturating<u32>` so we don't have to worry about overflow.
-        self.num_attempts += 1;
+        let now = clock::now();
 
-        self.core
-            .nth_retry_at(attempt_num, now, self.deadline, &mut rng)
-            .map_err(|_e| {
-                Error::TimedOut(TimeoutError {
-                    last_error: self
-                        .last_error
-                        .take()
-                        .expect("BUG: `last_error` should not be `None` here"),
-                })
-            })
-            .map(|retry_at| cmp::max(retry_at, self.next_retry_at.take()))
+        self.deadline = self.paused_deadline_at(now);
+        self.paused_for += now.saturating_duration_since(paused_at);
     }
 
-    fn wrap_result<T>(&mut self, result: Result<T, Error<E>>) -> ResultWrapper<'_, T, E> {
-        ResultWrapper {
-            result,
-            ease_off: self,
-        }
+    /// Returns `true` if [`Self::pause()`] has been called without a subsequent
+    /// [`Self::resume()`].
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
     }
-}
 
-impl<E> EaseOff<E> {
-    /// Attempt a blocking operation.
+    /// Restart this instance in place, as if it had just been started with the same timeout,
+    /// e.g. to reuse pooled instances across many operations.
     ///
-    /// If the operation previously failed, sleeps for the prescribed backoff period
-    /// using [`std::thread::sleep()`].
+    /// The new deadline, if any, is as far from now as the previous deadline
+    /// was from [`Self::started_at()`] (so [deadline jitter][Options::deadline_jitter()],
+    /// if any, is not chosen again). Use [`Self::reset_with_deadline()`] to set a new one.
     ///
-    /// ### Note: Behavior at Deadline
-    /// Most blocking operations cannot be cancelled once begun, so the [deadline][Self::deadline],
-    /// if set, is only checked *before* attempting the operation.
+    /// This is cheaper than starting a new instance, as the [`EaseOffCore`] and the functions
+    /// set on this instance are kept rather than copied or set again,
+    /// and no random number generator is acquired.
     ///
-    /// Generally, the only kinds of blocking operations that support cancellation
-    /// take an explicit timeout (such as setting a read timeout on a socket).
+    /// ### Cleared
+    /// * [`Self::started_at()`] is set to now, and the instance is no longer [paused][Self::pause()].
+    /// * The number of attempts, the previous error, and any retry time or delay set for the next
+    ///   attempt (e.g. by [`PollAfter::Pending`]); the next attempt is the first.
+    /// * All accumulated statistics: [`Self::summary()`], [`Self::delay_summary()`],
+    ///   [`Self::realized_delays()`], [`Self::last_attempt_duration()`]
+    ///   and [`Self::total_attempt_duration()`].
+    /// * The consecutive timeouts counted for [`Options::max_consecutive_timeouts()`],
+    ///   the distinct errors seen for [`Options::max_distinct_errors()`],
+    ///   and the errors kept by [`Self::keep_recent_errors()`].
     ///
-    /// If you want a blocking operation to be cancelled immediately once the deadline elapses,
-    /// consult the documentation for the API you are calling to see if timeouts are supported,
-    /// and if so, how to configure them.
-    pub fn try_blocking<T>(
-        &mut self,
-        op: impl FnOnce() -> Result<T, E>,
-    ) -> ResultWrapper<'_, T, E> {
-        match self.next_retry_at() {
-            Ok(Some(instant)) => {
-                blocking_sleep_until(instant);
-            }
-            Ok(None) => (),
-            Err(e) => return self.wrap_result(Err(e)),
-        }
+    /// ### Retained
+    /// * The [`EaseOffCore`], including its options, with [`Options::base_spread()`]
+    ///   already applied.
+    /// * The functions set by [`Self::on_zero_attempts()`], [`Self::on_failed_attempt()`],
+    ///   [`Self::fatal_on()`], [`Self::distinct_errors_by()`], the [`RetryLimiter`] and the
+    ///   `governor` rate limiter, if set, and the limits set by [`Self::keep_recent_errors()`]
+    ///   and [`Self::with_history()`].
+    /// * Any fields set by `with_fields()` (requires the `log` feature).
+    /// * The one-shot functions set by [`Self::on_max_delay_reached()`] and
+    ///   [`Self::on_soft_deadline()`], _only_ if they have not been called yet.
+    /// * Any cleanup pending from an async [`on_failed_attempt`][Self::on_failed_attempt()]
+    ///   function, which still runs before the next attempt.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+    ///
+    /// for _ in 0..3 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// let started_at = ease_off.started_at();
+    /// ease_off.reset();
+    ///
+    /// assert_eq!(ease_off.num_attempts(), 0);
+    /// assert!(ease_off.started_at() > started_at);
+    /// assert_eq!(ease_off.deadline(), Some(ease_off.started_at() + Duration::from_secs(10)));
+    /// ```
+    pub fn reset(&mut self) {
+        let now = clock::now();
 
-        self.wrap_result(op().map_err(Error::MaybeRetryable))
-    }
-}
+        let deadline = self.deadline.map(|deadline| {
+            clock::saturating_add(now, deadline.saturating_duration_since(self.started_at))
+        });
 
-/// Wrapper for [`Result`] returned from methods on [`EaseOff`].
-///
-/// Retryable errors will be stored in the `EaseOff` to be returned on the next attempt
-/// if the [deadline][EaseOff::deadline] has passed.
-#[must_use = "`.or_retry()` or `.or_retry_if()` must be called"]
-pub struct ResultWrapper<'a, T, E: 'a> {
-    result: Result<T, Error<E>>,
-    ease_off: &'a mut EaseOff<E>,
-}
+        self.reset_at(now, deadline);
+    }
 
-impl<'a, T, E: 'a> ResultWrapper<'a, T, E> {
-    /// Convert a [`TimeoutError`], if applicable, to another [`Error`] variant.
-    ///
-    /// May be used to convert a timeout error into [`Error::MaybeRetryable`].
+    /// Like [`Self::reset()`], but with the given deadline, or none.
     ///
-    /// If not otherwise handled, `.or_retry()` and `.or_retry_if()` will return the error.
-    pub fn on_timeout(
-        self,
-        on_timeout: impl FnOnce(TimeoutError<E>) -> Error<E>,
-    ) -> ResultWrapper<'a, T, E> {
-        Self {
-            result: self.result.map_err(|e| e.on_timeout(on_timeout)),
-            ease_off: self.ease_off,
+    /// [`Options::deadline_jitter()`] is applied to the new deadline, as when starting
+    /// an instance with [`Options::start_deadline_opt()`].
+    pub fn reset_with_deadline(&mut self, deadline: Option<Instant>) {
+        let now = clock::now();
+
+        let deadline = deadline.map(|deadline| {
+            self.core
+                .jitter_deadline(now, deadline, &mut rng::LazyRng::new())
+        });
+
+        self.reset_at(now, deadline);
+    }
+
+    fn reset_at(&mut self, now: Instant, deadline: Option<Instant>) {
+        self.started_at = now;
+        self.deadline = deadline;
+        self.paused_at = None;
+        self.paused_for = Duration::ZERO;
+        self.num_attempts = Saturating(0);
+        self.attempt_was_delayed = false;
+        self.total_slept = Duration::ZERO;
+        self.delay_summary = DelaySummary::default();
+        // Keeps the allocation.
+        self.realized_delays.clear();
+        self.last_attempt_duration = None;
+        self.total_attempt_duration = Duration::ZERO;
+        self.consecutive_timeouts = 0;
+        self.last_error = None;
+        self.not_ready_error = None;
+        self.resumed = false;
+        self.next_retry_at = None;
+        self.next_delay = None;
+        // Keeps the allocation.
+        self.distinct_errors.clear();
+
+        if let Some(recent) = &mut self.recent_errors {
+            recent.errors.clear();
         }
     }
 
-    /// Inspect the error if the operation failed.
+    /// Returns the time elapsed since this instance was started, excluding time spent paused.
+    fn elapsed_at(&self, now: Instant) -> Duration {
+        let paused_for = self.paused_for
+            + self.paused_at.map_or(Duration::ZERO, |paused_at| {
+                now.saturating_duration_since(paused_at)
+            });
+
+        now.saturating_duration_since(self.started_at)
+            .saturating_sub(paused_for)
+    }
+
+    /// Returns the deadline, accounting for the current pause if applicable,
+    /// and for the time slept if [`Options::deadline_excludes_sleep()`] is set.
     ///
-    /// This could also be [`Error::TimedOut`] containing an error from a previous iteration.
-    pub fn inspect_err(self, inspect_err: impl FnOnce(&Error<E>)) -> Self {
-        Self {
-            result: self.result.inspect_err(inspect_err),
-            ease_off: self.ease_off,
+    /// If the deadline overflows, it is treated as if there is no deadline.
+    pub(crate) fn deadline_at(&self, now: Instant) -> Option<Instant> {
+        let deadline = self.paused_deadline_at(now)?;
+
+        if self.core.options().deadline_excludes_sleep {
+            // `DelaySummary::total` is not reset on success, unlike `total_slept`.
+            return deadline.checked_add(self.delay_summary.total);
         }
+
+        Some(deadline)
     }
 
-    /// Check the result, testing the error for retryability using [`RetryableError`] if applicable.
+    /// Returns the deadline, accounting only for the current pause if applicable.
     ///
-    /// If the operation was successful, `Ok(Some(_))` is returned.
-    ///
-    /// If the operation failed but [`RetryableError::can_retry()`] returned `true`,
-    /// `Ok(None)` is returned and the error is stored in the [`EaseOff`] instance for the next
-    /// iteration.
+    /// Unlike [`Self::deadline_at()`], this is suitable for storing back into `self.deadline`,
+    /// as the time slept is added again on every check.
+    fn paused_deadline_at(&self, now: Instant) -> Option<Instant> {
+        let deadline = self.deadline?;
+
+        match self.paused_at {
+            Some(paused_at) => deadline.checked_add(now.saturating_duration_since(paused_at)),
+            None => Some(deadline),
+        }
+    }
+
+    /// Returns the number of attempts that have been made.
     ///
-    /// If the error was determined to be fatal or the [deadline][EaseOff::deadline()] has elapsed,
-    /// `Err` is returned.
-    pub fn or_retry(self) -> Result<Option<T>, E>
-    where
-        E: RetryableError,
-    {
-        self.or_retry_if(RetryableError::can_retry)
+    /// Saturates at [`u32::MAX`].
+    #[inline(always)]
+    pub fn num_attempts(&self) -> u32 {
+        self.num_attempts.0
     }
 
-    /// Check the result, testing the error for retryability using the given closure if applicable.
+    /// Returns the number of consecutive attempts that failed with a timeout.
     ///
-    /// The closure will be invoked with either the error from the current attempt,
-    /// or a previous error if the [deadline][EaseOff::deadline()] has elapsed
-    /// (this is indicated by the [`Error::TimedOut`] variant).
+    /// See [`Options::max_consecutive_timeouts()`] for details.
+    #[inline(always)]
+    pub fn consecutive_timeouts(&self) -> u32 {
+        self.consecutive_timeouts
+    }
+
+    /// Returns `true` if the most recent attempt waited for a backoff delay before running.
     ///
-    /// If the operation was successful, `Ok(Some(_))` is returned.
+    /// This distinguishes a retry that slept from one that ran immediately,
+    /// e.g. the first attempt or a retry with no delay, which the attempt number alone
+    /// cannot do when [`Options::initial_jitter()`] or [`Options::delay_override()`] is used.
     ///
-    /// If the operation failed but the given closure returns `true`,
-    /// `Ok(None)` is returned and the error is stored in the [`EaseOff`] instance for the next
-    /// iteration.
+    /// Returns `false` if no attempt has been made yet, or if the most recent `try_*` call
+    /// returned an error without running the operation (e.g. because the deadline elapsed).
     ///
-    /// If the error was determined to be fatal, `Err` is returned.
-    pub fn or_retry_if(self, can_retry: impl FnOnce(&Error<E>) -> bool) -> Result<Option<T>, E> {
-        self.or_retry_with(|e| {
-            if can_retry(e) {
-                ControlFlow::Continue(None)
-            } else {
-                ControlFlow::Break(())
-            }
-        })
-    }
-
-    /// Check the result, testing the error for retryability using the given closure if applicable.
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
     ///
-    /// The closure will be invoked with either the error from the current attempt,
-    /// or a previous error if the [deadline][EaseOff::deadline()] has elapsed
-    /// (this is indicated by the [`Error::TimedOut`] variant).
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .start_unlimited();
     ///
-    /// If the operation was successful, `Ok(Some(_))` is returned.
+    /// for expected in [false, false, true] {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|_e| true);
     ///
-    /// If the operation failed but the given closure returns `ControlFlow::Continue`,
-    /// `Ok(None)` is returned and the error is stored in the [`EaseOff`] instance for the next
-    /// iteration.
+    ///     assert_eq!(ease_off.attempt_was_delayed(), expected);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn attempt_was_delayed(&self) -> bool {
+        self.attempt_was_delayed
+    }
+
+    /// Returns a concise summary of the current state, suitable for logging.
     ///
-    /// If the given error specifies a retry time,
-    /// the closure may return `Control::Continue(Some(retry_at))`
-    /// with the next attempt waiting until `retry_at` or the current backoff,
-    /// whichever is later.
+    /// The [`Display`][fmt::Display] output of the returned value looks like:
     ///
-    /// Otherwise, the closure should return `ControlFlow::Continue(None)` to use the
-    /// next backoff time.
+    /// ```text
+    /// EaseOff { attempts: 3, elapsed: 2.1s, remaining: 57.9s, next_delay: ~600.0ms }
+    /// ```
     ///
-    /// If the error is fatal, the closure should return `ControlFlow::Break(())`
-    /// and then `Err` is returned.
-    pub fn or_retry_with(
-        self,
-        should_retry: impl FnOnce(&Error<E>) -> ControlFlow<(), Option<Instant>>,
-    ) -> Result<Option<T>, E> {
-        match self.result {
-            Ok(success) => {
-                self.ease_off.last_error = None;
-                self.ease_off.next_retry_at = None;
-                Ok(Some(success))
-            }
-            Err(e) => match should_retry(&e) {
-                ControlFlow::Continue(next_retry_at) => {
-                    self.ease_off.last_error = Some(e.into_inner());
-                    self.ease_off.next_retry_at = next_retry_at;
+    /// The fields are also public for structured logging.
+    pub fn summary(&self) -> Summary {
+        let now = clock::now();
 
-                    Ok(None)
-                }
-                ControlFlow::Break(()) => Err(e.into_inner()),
+        let n = if self.is_backing_off() {
+            self.num_attempts.0
+        } else {
+            0
+        };
+
+        // `None` compares less than `Some`
+        let next_delay = cmp::max(
+            match self.next_delay {
+                Some(delay) => Some(cmp::min(delay, self.core.options().max_delay)),
+                None => self.core.nth_base_delay(n).map(|base| base.delay),
             },
+            self.next_retry_at
+                .map(|retry_at| retry_at.saturating_duration_since(now)),
+        );
+
+        Summary {
+            attempts: self.num_attempts.0,
+            elapsed: self.elapsed_at(now),
+            remaining: self
+                .deadline_at(now)
+                .map(|deadline| deadline.saturating_duration_since(now)),
+            next_delay,
         }
     }
-}
 
-/// Trait which may be implemented for error types to enable code reuse with [`EaseOff`].
-pub trait RetryableError {
-    /// Returns `true` if the error is non-fatal, `false` otherwise.
-    fn can_retry(&self) -> bool;
-}
+    /// Returns statistics about the attempts leading up to a success, suitable for logging.
+    ///
+    /// This is intended to be called after `.or_retry()` (or one of its variants)
+    /// returns `Ok(Some(_))`, at which point it describes the attempts made since the previous
+    /// success (or since the `EaseOff` was started). It remains valid until the next attempt.
+    ///
+    /// If called at any other time, it describes the attempts made so far.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(10))
+    ///     .start_timeout(Duration::from_secs(10));
+    ///
+    /// let mut attempts = 0;
+    ///
+    /// let message = loop {
+    ///     let Some(message) = ease_off
+    ///         .try_blocking(|| {
+    ///             attempts += 1;
+    ///
+    ///             if attempts < 3 {
+    ///                 Err("not yet")
+    ///             } else {
+    ///                 Ok("done")
+    ///             }
+    ///         })
+    ///         .or_retry_if(|_e| true)?
+    ///     else {
+    ///         continue;
+    ///     };
+    ///
+    ///     break message;
+    /// };
+    ///
+    /// let stats = ease_off.succeeded();
+    ///
+    /// assert_eq!(message, "done");
+    /// assert_eq!(stats.attempts, 3);
+    /// assert!(stats.total_slept > Duration::ZERO);
+    /// assert!(stats.elapsed >= stats.total_slept);
+    ///
+    /// // e.g. "succeeded after 3 attempts in 12.3ms (slept 11.9ms)"
+    /// println!("succeeded after {stats}");
+    /// # Ok::<_, &str>(())
+    /// ```
+    pub fn succeeded(&self) -> SuccessStats {
+        SuccessStats {
+            attempts: self.num_attempts.0.saturating_add(1),
+            elapsed: self.elapsed_at(clock::now()),
+            total_slept: self.total_slept,
+        }
+    }
 
-/// Error type for [`EaseOff`] which includes the fatality level of the error.
-#[derive(Debug)]
-pub enum Error<E> {
-    /// The inner error has not been determined to be fatal yet.
+    /// Returns a summary of the backoff delays this instance has produced,
+    /// e.g. to feed into an outer control loop which tunes the [`Options`].
     ///
-    /// [`RetryableError::can_retry()`] passes through to the inner error.
-    MaybeRetryable(E),
-    /// The error was determined to be fatal.
+    /// Only attempts that were delayed are counted (see [`Self::attempt_was_delayed()`]);
+    /// attempts made immediately are not. Each delay is the time from when it was
+    /// scheduled until the attempt was due, with jitter applied, and is recorded in
+    /// constant time without storing the individual delays.
     ///
-    /// Always returns `false` from [`RetryableError::can_retry()`].
-    Fatal(E),
-    /// The [deadline][EaseOff::deadline()] has elapsed.
+    /// Unlike [`Self::succeeded()`], the summary covers the whole lifetime of this instance:
+    /// it is _not_ reset after a successful attempt, even if [`Options::reset_on_success()`]
+    /// is set.
     ///
-    /// Contained is the error from the most recent attempt.
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
     ///
-    /// Always returns `false` from [`RetryableError::can_retry()`].
-    TimedOut(TimeoutError<E>),
-}
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(2))
+    ///     .start_unlimited();
+    ///
+    /// for _ in 0..4 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// let summary = ease_off.delay_summary();
+    ///
+    /// // The first attempt and the first retry are made immediately.
+    /// assert_eq!(summary.count, 2);
+    /// assert!(summary.max > Duration::ZERO && summary.max < Duration::from_millis(5));
+    /// assert!(summary.mean() <= summary.max);
+    /// ```
+    #[inline(always)]
+    pub fn delay_summary(&self) -> DelaySummary {
+        self.delay_summary
+    }
 
-/// Error wrapper type indicating a failure due to a [deadline][EaseOff::deadline()] elapsing.
-#[derive(Debug)]
+    /// Returns the most recent delays this instance has slept for before attempts, oldest first,
+    /// if enabled with [`Self::with_history()`]; otherwise, empty.
+    ///
+    /// This is the schedule actually realized by this run, after jitter, overrides
+    /// and any delay requested by the operation itself (e.g. with [`PollAfter::Pending`]),
+    /// as opposed to the schedule calculated by [`EaseOffCore::nth_retry_at()`].
+    /// Like [`Self::delay_summary()`], only attempts which were delayed are recorded,
+    /// and the list is not cleared after a successful attempt.
+    ///
+    /// See [`Self::with_history()`] for an example.
+    #[inline(always)]
+    pub fn realized_delays(&self) -> &VecDeque<Duration> {
+        &self.realized_delays
+    }
+
+    /// Returns how long the most recent attempt took, or `None` if no attempt has been made.
+    ///
+    /// This times only the operation itself, from just before it is invoked (after any
+    /// backoff sleep) until it returns; compare it with [`Self::delay_summary()`]
+    /// to see whether the operation or the backoff dominates.
+    /// For async operations, this includes any time the future spent waiting to be polled.
+    ///
+    /// Attempts that were never started, e.g. because the deadline had elapsed, are not timed.
+    /// An attempt cancelled by [`TryAsync::enforce_deadline_with()`] or
+    /// [`TryAsync::timeout_after()`] is timed until it was cancelled.
+    ///
+    /// [`TryAsync::enforce_deadline_with()`]: crate::futures::TryAsync::enforce_deadline_with()
+    /// [`TryAsync::timeout_after()`]: crate::futures::TryAsync::timeout_after()
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut ease_off = EaseOff::start_unlimited();
+    /// assert_eq!(ease_off.last_attempt_duration(), None);
+    ///
+    /// for _ in 0..3 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| {
+    ///             std::thread::sleep(Duration::from_millis(5));
+    ///             Err::<(), _>("oops")
+    ///         })
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// assert!(ease_off.last_attempt_duration().unwrap() >= Duration::from_millis(5));
+    /// assert!(ease_off.total_attempt_duration() >= Duration::from_millis(15));
+    /// ```
+    #[inline(always)]
+    pub fn last_attempt_duration(&self) -> Option<Duration> {
+        self.last_attempt_duration
+    }
+
+    /// Returns the total time spent in attempts so far, excluding the sleeps between them.
+    ///
+    /// See [`Self::last_attempt_duration()`] for what is timed. Like [`Self::delay_summary()`],
+    /// this covers the whole lifetime of this instance and is not reset after a successful
+    /// attempt.
+    #[inline(always)]
+    pub fn total_attempt_duration(&self) -> Duration {
+        self.total_attempt_duration
+    }
+
+    /// Returns when the next attempt would be made if it were started now,
+    /// without sleeping or changing any state.
+    ///
+    /// This allows integrating with an external timer or scheduler: wait until the returned
+    /// instant, then call `try_*` as normal. `Ok(None)` means the next attempt may be made
+    /// immediately.
+    ///
+    /// ### Note: Jitter
+    /// Jitter is not applied, so the returned instant is the latest that the next attempt
+    /// may be scheduled for; the actual attempt may be scheduled slightly earlier.
+    /// This way, peeking repeatedly returns a consistent answer.
+    ///
+    /// ### Errors
+    /// Returns [`Error::TimedOut`] with a clone of the previous error if the next attempt
+    /// would be scheduled after the [deadline][Self::deadline].
+    ///
+    /// The [`RetryLimiter`] and the `governor` rate limiter, if set, are not consulted,
+    /// as that would use up a permit.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(10))
+    ///     .start_unlimited();
+    ///
+    /// // The first attempt is made immediately.
+    /// assert!(matches!(ease_off.next_retry_at_peek(), Ok(None)));
+    ///
+    /// for _ in 0..2 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| -> Result<(), _> { Err("oops") })
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// let num_attempts = ease_off.num_attempts();
+    ///
+    /// let retry_at = ease_off.next_retry_at_peek().unwrap().expect("should back off");
+    /// assert!(retry_at > Instant::now());
+    ///
+    /// // Peeking does not count as an attempt.
+    /// assert_eq!(ease_off.num_attempts(), num_attempts);
+    /// ```
+    pub fn next_retry_at_peek(&self) -> Result<Option<Instant>, Error<E>>
+    where
+        E: Clone,
+    {
+        let now = clock::now();
+
+        // Always yields zero, so no jitter is applied.
+        let mut rng = rand::rngs::mock::StepRng::new(0, 0);
+
+        if !self.is_backing_off() {
+            if let Some(e) = self.zero_attempts_error(now) {
+                return Err(e);
+            }
+
+            return Ok(cmp::max(
+                self.core
+                    .nth_retry_at(0, now, None, &mut rng)
+                    .expect("passed `None` for deadline, should not be `Err`"),
+                self.next_retry_at,
+            ));
+        }
+
+        let Ok(retry_at) = self.backoff_retry_at(self.num_attempts.0, now, &mut rng) else {
+            let last_error = self
+                .last_error
+                .as_ref()
+                .or(self.not_ready_error.as_ref())
+                .expect("BUG: backing off without an error")
+                .clone();
+
+            return Err(Error::TimedOut(TimeoutError {
+                last_error,
+                recent_errors: self
+                    .recent_errors
+                    .as_ref()
+                    .map_or_else(Vec::new, |recent| recent.errors.iter().cloned().collect()),
+            }));
+        };
+
+        Ok(cmp::max(retry_at, self.next_retry_at))
+    }
+
+    /// Skip the first attempt if the deadline has already elapsed, returning [`Error::TimedOut`]
+    /// with the error produced by `make_error` instead.
+    ///
+    /// This turns on [`Options::allow_zero_attempts()`] for this instance,
+    /// whether or not it was set in the options; see that method for details.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{EaseOff, Error, Options};
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .start_timeout(Duration::ZERO)
+    ///     .on_zero_attempts(|| "deadline elapsed before the first attempt");
+    ///
+    /// let result = ease_off
+    ///     .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+    ///     .inspect_err(|e| assert!(matches!(e, Error::TimedOut(_))))
+    ///     .or_retry_if(|_e| false);
+    ///
+    /// assert_eq!(result.unwrap_err(), "deadline elapsed before the first attempt");
+    /// ```
+    pub fn on_zero_attempts(mut self, make_error: fn() -> E) -> Self {
+        self.make_zero_attempts_error = Some(make_error);
+        self
+    }
+
+    /// Limit retries using the given [`RetryLimiter`], which may be shared with other instances.
+    ///
+    /// See the docs of [`RetryLimiter`] for details.
+    pub fn with_limiter(mut self, limiter: Arc<RetryLimiter>) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Delay attempts until the given [`governor`] rate limiter allows them.
+    ///
+    /// The limiter may be shared with other instances, or with other code making requests
+    /// to the same service, so that the backoff respects a global rate limit.
+    ///
+    /// Before every attempt, including the first, the limiter is checked using
+    /// [`RateLimiter::check()`][::governor::RateLimiter::check()], after any backoff sleep.
+    /// If it denies the attempt, this sleeps until the earliest time the limiter would allow it,
+    /// then checks again, as another user of the limiter may have taken the capacity first.
+    ///
+    /// Unlike a [`RetryLimiter`], the limiter does not cause the operation to fail by itself,
+    /// but a retry fails with [`Error::TimedOut`] if the limiter would not allow it
+    /// before the [deadline][Self::deadline()]. The first attempt is made regardless.
+    ///
+    /// Replaces any rate limiter previously set by this method.
+    ///
+    /// ```rust
+    /// use std::num::NonZeroU32;
+    /// use std::sync::Arc;
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::EaseOff;
+    /// use governor::{Quota, RateLimiter};
+    ///
+    /// // At most 20 attempts per second, shared by all instances.
+    /// let limiter = Arc::new(RateLimiter::direct(Quota::per_second(NonZeroU32::new(20).unwrap())));
+    ///
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10)).with_governor(limiter);
+    ///
+    /// let started_at = Instant::now();
+    ///
+    /// for _ in 0..25 {
+    ///     let result = ease_off
+    ///         .try_blocking(|| Ok::<_, &str>(()))
+    ///         .or_retry_if(|_e| true);
+    ///
+    ///     assert_eq!(result, Ok(Some(())));
+    /// }
+    ///
+    /// // The burst of 20 attempts was allowed immediately; the rest were delayed.
+    /// assert!(started_at.elapsed() >= Duration::from_millis(100));
+    /// ```
+    #[cfg(feature = "governor")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "governor")))]
+    pub fn with_governor<S, C>(
+        mut self,
+        limiter: Arc<
+            ::governor::RateLimiter<
+                ::governor::state::NotKeyed,
+                S,
+                C,
+                ::governor::middleware::NoOpMiddleware<C::Instant>,
+            >,
+        >,
+    ) -> Self
+    where
+        S: ::governor::state::DirectStateStore + Send + Sync + 'static,
+        C: ::governor::clock::Clock + Send + Sync + 'static,
+        C::Instant: Send + Sync,
+    {
+        self.governor = Some(GovernorCheck(Box::new(move |now| {
+            let not_until = limiter.check().err()?;
+            let wait = not_until.wait_time_from(limiter.clock().now());
+
+            Some(clock::saturating_add(now, wait))
+        })));
+        self
+    }
+
+    /// Set a function to run after each failed attempt, e.g. to reset state or close
+    /// a broken connection before the next attempt.
+    ///
+    /// The function is invoked with the error when it is recorded for retrying,
+    /// i.e. when `.or_retry()` (or one of its variants) returns `Ok(None)`
+    /// after the operation failed. It is called after any [`ResultWrapper::inspect_err()`]
+    /// in the same chain, and before the backoff sleep preceding the next attempt.
+    ///
+    /// It is not called for fatal errors, or if the [deadline][Self::deadline()] has elapsed.
+    ///
+    /// Replaces any function previously set by this method or its async equivalent.
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use std::sync::Arc;
+    /// use ease_off::EaseOff;
+    ///
+    /// let reconnects = Arc::new(AtomicU32::new(0));
+    ///
+    /// let mut ease_off = EaseOff::start_unlimited().on_failed_attempt({
+    ///     let reconnects = reconnects.clone();
+    ///     move |_e: &String| {
+    ///         reconnects.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// });
+    ///
+    /// let mut attempts = 0;
+    ///
+    /// while ease_off
+    ///     .try_blocking(|| {
+    ///         attempts += 1;
+    ///
+    ///         if attempts < 3 {
+    ///             Err("connection reset".to_string())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .or_retry_if(|_e| true)
+    ///     .unwrap()
+    ///     .is_none()
+    /// {}
+    ///
+    /// assert_eq!(reconnects.load(Ordering::Relaxed), 2);
+    /// ```
+    pub fn on_failed_attempt(
+        mut self,
+        on_failed_attempt: impl FnMut(&E) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_failed_attempt = Some(OnFailedAttempt::Blocking(Box::new(on_failed_attempt)));
+        self
+    }
+
+    /// Set a function to determine if an error is always fatal, regardless of any other checks.
+    ///
+    /// The function is invoked with the error from each failed attempt. If it returns `true`,
+    /// the error is converted to [`Error::Fatal`] and returned from `.or_retry()`
+    /// (or one of its variants) without being checked for retryability,
+    /// as if [`ResultWrapper::classify_once()`] returned [`Fatality::Fatal`].
+    ///
+    /// This allows application-specific exceptions to be layered on top of
+    /// a reusable [`RetryableError`] implementation.
+    ///
+    /// Replaces any function previously set by this method.
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use ease_off::{EaseOff, RetryableError};
+    ///
+    /// struct MyError(io::ErrorKind);
+    ///
+    /// impl RetryableError for MyError {
+    ///     fn can_retry(&self) -> bool {
+    ///         // Anything could be transient.
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let mut ease_off = EaseOff::start_unlimited()
+    ///     // ...except for this application.
+    ///     .fatal_on(|e: &MyError| e.0 == io::ErrorKind::PermissionDenied);
+    ///
+    /// let result = ease_off
+    ///     .try_blocking(|| Err::<(), _>(MyError(io::ErrorKind::PermissionDenied)))
+    ///     .or_retry();
+    ///
+    /// assert!(matches!(result, Err(MyError(io::ErrorKind::PermissionDenied))));
+    /// ```
+    pub fn fatal_on(mut self, fatal_on: impl Fn(&E) -> bool + Send + Sync + 'static) -> Self {
+        self.fatal_on = Some(FatalOn(Box::new(fatal_on)));
+        self
+    }
+
+    /// Set the function which extracts the key deciding whether two errors are distinct,
+    /// for [`Options::max_distinct_errors()`]; see that method for details.
+    ///
+    /// Errors are distinct if their keys hash differently using the standard library's
+    /// default hasher. Hash collisions are possible but vanishingly unlikely,
+    /// and would only cause two distinct errors to be counted once.
+    ///
+    /// Replaces any function previously set by this method.
+    pub fn distinct_errors_by<K: Hash>(
+        mut self,
+        key: impl Fn(&E) -> K + Send + Sync + 'static,
+    ) -> Self {
+        self.distinct_errors_by = Some(ErrorKey(Box::new(move |e| {
+            let mut hasher = DefaultHasher::new();
+            key(e).hash(&mut hasher);
+            hasher.finish()
+        })));
+        self
+    }
+
+    /// Keep up to `limit` errors from the failed attempts before the last one,
+    /// to be returned in [`TimeoutError::recent_errors`] if the deadline elapses.
+    ///
+    /// This shows how the operation was failing, not just how it failed last:
+    ///
+    /// ```text
+    /// deadline elapsed; last error: C; recent errors: [A, B]
+    /// ```
+    ///
+    /// Disabled (a limit of zero) by default.
+    ///
+    /// ### Memory
+    /// Errors are formatted using [`Display`][fmt::Display] when the next attempt fails,
+    /// so `E` need not be `Clone`. At most `limit` strings are kept, discarding the oldest first,
+    /// in a buffer which grows as needed up to `limit` entries.
+    /// [`Options::history_capacity()`], if smaller, caps the buffer instead.
+    ///
+    /// The errors are cleared after a successful attempt, and moved into the [`TimeoutError`]
+    /// when it is returned. They are not included in other errors.
+    ///
+    /// Replaces any limit previously set by this method, discarding any errors already kept.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let started_at = Instant::now() - Duration::from_secs(3600);
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .start_deadline_from::<&str>(started_at, started_at + Duration::from_secs(10))
+    ///     .keep_recent_errors(2);
+    ///
+    /// for (secs, error) in [(0, "A"), (1, "B"), (2, "C"), (3, "D")] {
+    ///     let result = ease_off
+    ///         .try_blocking_at(started_at + Duration::from_secs(secs), || Err::<(), _>(error))
+    ///         .or_retry_if(|e| e.is_maybe_retryable());
+    ///
+    ///     assert_eq!(result, Ok(None));
+    /// }
+    ///
+    /// // The deadline has elapsed, so no attempt is made.
+    /// let error = ease_off
+    ///     .try_blocking_at(started_at + Duration::from_secs(60), || Err::<(), _>("E"))
+    ///     .inspect_err(|e| {
+    ///         assert_eq!(
+    ///             e.to_string(),
+    ///             "deadline elapsed; last error: D; recent errors: [B, C]"
+    ///         );
+    ///     })
+    ///     .or_retry_if(|e| e.is_maybe_retryable())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(error, "D");
+    /// ```
+    pub fn keep_recent_errors(mut self, limit: usize) -> Self
+    where
+        E: fmt::Display,
+    {
+        self.recent_errors = (limit > 0).then(|| RecentErrors {
+            limit,
+            to_string: E::to_string,
+            errors: VecDeque::new(),
+        });
+        self
+    }
+
+    /// Keep up to `limit` of the most recent delays slept before attempts,
+    /// to be returned by [`Self::realized_delays()`].
+    ///
+    /// Disabled (a limit of zero) by default, so that no memory is allocated.
+    ///
+    /// Only the most recent `limit` delays are kept; once the limit is reached, the oldest delay
+    /// is dropped to make room for each new one, though all delays are still counted in
+    /// [`Self::delay_summary()`]. [`Options::history_capacity()`], if smaller,
+    /// caps the recorded delays instead.
+    /// The recorded delays are cleared by [`Self::reset()`], but not after a successful attempt.
+    ///
+    /// Replaces any limit previously set by this method, dropping the oldest recorded delays
+    /// if necessary.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .jitter(0.0)
+    ///     .start_unlimited()
+    ///     .with_history(3);
+    ///
+    /// for _ in 0..6 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// // The first attempt and the first retry are made immediately,
+    /// // and the first delay (1ms) has been dropped.
+    /// assert_eq!(
+    ///     *ease_off.realized_delays(),
+    ///     [2, 4, 8].map(Duration::from_millis),
+    /// );
+    /// assert_eq!(ease_off.delay_summary().count, 4);
+    ///
+    /// ease_off.reset();
+    /// assert!(ease_off.realized_delays().is_empty());
+    /// ```
+    pub fn with_history(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+
+        let excess = self.realized_delays.len().saturating_sub(limit);
+        self.realized_delays.drain(..excess);
+        self
+    }
+
+    /// Attach key-value fields, e.g. a request ID or endpoint, to every event this instance
+    /// logs using the `log` facade.
+    ///
+    /// Each field is a pair of a `&'static str` key and a value formatted as a `String`,
+    /// and is attached to the events as [structured key-values][log::kv], so the logger
+    /// decides how to display them. This avoids threading context into every
+    /// [`ResultWrapper::inspect_err()`] just to identify which operation is retrying.
+    ///
+    /// Fields are attached in the given order, after any fields set by previous calls.
+    /// They are kept by [`Self::reset()`] and [`Self::map_err()`].
+    ///
+    /// Only events logged using `log` include the fields; `tracing` is not supported.
+    /// The only event emitted using `tracing`, the warning enabled by
+    /// [`Options::warn_on_thundering_herd()`], is emitted when the instance is started,
+    /// before fields can be set.
+    ///
+    /// ```rust
+    /// use std::sync::Mutex;
+    /// use ease_off::EaseOff;
+    /// use log::kv::{Error, Key, Value, VisitSource};
+    ///
+    /// // Records the fields of each event.
+    /// struct Logger(Mutex<Vec<String>>);
+    ///
+    /// impl log::Log for Logger {
+    ///     fn enabled(&self, _: &log::Metadata) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn log(&self, record: &log::Record) {
+    ///         struct Visitor(String);
+    ///
+    ///         impl<'kvs> VisitSource<'kvs> for Visitor {
+    ///             fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+    ///                 self.0 += &format!(" {key}={value}");
+    ///                 Ok(())
+    ///             }
+    ///         }
+    ///
+    ///         let mut visitor = Visitor(record.args().to_string());
+    ///         record.key_values().visit(&mut visitor).unwrap();
+    ///         self.0.lock().unwrap().push(visitor.0);
+    ///     }
+    ///
+    ///     fn flush(&self) {}
+    /// }
+    ///
+    /// static LOGGER: Logger = Logger(Mutex::new(Vec::new()));
+    ///
+    /// log::set_logger(&LOGGER).unwrap();
+    /// log::set_max_level(log::LevelFilter::Debug);
+    ///
+    /// let mut ease_off = EaseOff::start_unlimited()
+    ///     .with_fields([("request_id", "f00d"), ("endpoint", "/api/status")]);
+    ///
+    /// let result = ease_off
+    ///     .try_blocking(|| Err::<(), _>("oops"))
+    ///     .or_retry_if(|_e| true);
+    /// assert_eq!(result, Ok(None));
+    ///
+    /// assert_eq!(
+    ///     *LOGGER.0.lock().unwrap(),
+    ///     ["ease-off: attempt 1 failed; will retry request_id=f00d endpoint=/api/status"],
+    /// );
+    /// ```
+    #[cfg(feature = "log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+    pub fn with_fields(
+        mut self,
+        fields: impl IntoIterator<Item = (&'static str, impl Into<String>)>,
+    ) -> Self {
+        self.log_fields
+            .0
+            .extend(fields.into_iter().map(|(key, value)| (key, value.into())));
+        self
+    }
+
+    /// Set a function to run the first time the backoff reaches [`Options::max_delay()`].
+    ///
+    /// Reaching the maximum delay means the operation has been failing for long enough that
+    /// the backoff has plateaued, which indicates a sustained outage rather than a blip.
+    /// This is a useful signal for alerting, distinct from a threshold on the number of attempts.
+    ///
+    /// The function is called before the backoff sleep preceding the first attempt
+    /// whose calculated delay (before jitter) is capped at `max_delay`.
+    /// It is called at most once per instance, and is not called for a delay set by
+    /// [`PollAfter::Pending`].
+    ///
+    /// Replaces any function previously set by this method.
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let reached = Arc::new(AtomicBool::new(false));
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .max_delay(Duration::from_millis(3))
+    ///     .start_unlimited()
+    ///     .on_max_delay_reached({
+    ///         let reached = reached.clone();
+    ///         move || reached.store(true, Ordering::Relaxed)
+    ///     });
+    ///
+    /// // Delays before each attempt: 0ms, 0ms, 1ms, 2ms, 3ms (capped), ...
+    /// for attempt in 0..6 {
+    ///     assert_eq!(reached.load(Ordering::Relaxed), attempt > 4);
+    ///
+    ///     let _ = ease_off
+    ///         .try_blocking(|| -> Result<(), _> { Err("down") })
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// assert!(reached.load(Ordering::Relaxed));
+    /// ```
+    pub fn on_max_delay_reached(
+        mut self,
+        on_max_delay_reached: impl FnOnce() + Send + Sync + 'static,
+    ) -> Self {
+        self.on_max_delay_reached = Some(OnMaxDelayReached(Box::new(on_max_delay_reached)));
+        self
+    }
+
+    /// Set a function to run the first time the [soft deadline][Options::soft_deadline()]
+    /// is crossed.
+    ///
+    /// Unlike the deadline, the soft deadline does not stop retries; it is a point at which
+    /// to raise the alarm (e.g. log a warning or page on-call) while the operation keeps
+    /// retrying until the deadline, if any. This supports policies like
+    /// "warn at 10 seconds, give up at 60 seconds".
+    ///
+    /// The soft deadline is checked before each attempt (before the backoff sleep),
+    /// so the function is called before the first attempt started once
+    /// [`Options::soft_deadline()`] has elapsed since this instance was started,
+    /// not counting time spent [paused][Self::pause()]. It is called at most once per instance,
+    /// even if the schedule is reset after a success.
+    ///
+    /// Has no effect if [`Options::soft_deadline()`] is not set.
+    /// Replaces any function previously set by this method.
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let warnings = Arc::new(AtomicU32::new(0));
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(5))
+    ///     .multiplier(1.0)
+    ///     .jitter(0.0)
+    ///     .soft_deadline(Duration::from_millis(20))
+    ///     .start_timeout(Duration::from_secs(10))
+    ///     .on_soft_deadline({
+    ///         let warnings = warnings.clone();
+    ///         move || {
+    ///             warnings.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     });
+    ///
+    /// // Made over about 40ms.
+    /// for _ in 0..10 {
+    ///     let result = ease_off
+    ///         .try_blocking(|| Err::<(), _>("unavailable"))
+    ///         .or_retry_if(|_e| true);
+    ///
+    ///     // Retries continue past the soft deadline.
+    ///     assert_eq!(result, Ok(None));
+    /// }
+    ///
+    /// // But the function was only called once.
+    /// assert_eq!(warnings.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn on_soft_deadline(
+        mut self,
+        on_soft_deadline: impl FnOnce() + Send + Sync + 'static,
+    ) -> Self {
+        self.on_soft_deadline = Some(OnSoftDeadline(Box::new(on_soft_deadline)));
+        self
+    }
+
+    fn check_soft_deadline(&mut self, now: Instant) {
+        let Some(soft_deadline) = self.core.options().soft_deadline else {
+            return;
+        };
+
+        if self.on_soft_deadline.is_some() && self.elapsed_at(now) >= soft_deadline {
+            if let Some(OnSoftDeadline(on_soft_deadline)) = self.on_soft_deadline.take() {
+                on_soft_deadline();
+            }
+        }
+    }
+
+    fn record_failed_attempt(&mut self, error: E) {
+        #[cfg(feature = "log")]
+        self.log(
+            log::Level::Debug,
+            format_args!(
+                "ease-off: attempt {} failed; will retry",
+                self.num_attempts.0.saturating_add(1)
+            ),
+        );
+
+        // `error` takes precedence from now on.
+        self.not_ready_error = None;
+
+        match &mut self.on_failed_attempt {
+            Some(OnFailedAttempt::Blocking(on_failed_attempt)) => on_failed_attempt(&error),
+            #[cfg(feature = "futures")]
+            Some(OnFailedAttempt::Async(on_failed_attempt)) => {
+                self.pending_cleanup = Some(on_failed_attempt(&error));
+            }
+            None => (),
+        }
+
+        let capacity = self.core.options().history_capacity;
+
+        if let (Some(recent), Some(previous)) = (&mut self.recent_errors, &self.last_error) {
+            let limit = capacity.map_or(recent.limit, |cap| cmp::min(cap, recent.limit));
+
+            if limit > 0 {
+                while recent.errors.len() >= limit {
+                    recent.errors.pop_front();
+                }
+
+                recent.errors.push_back((recent.to_string)(previous));
+            }
+        }
+
+        self.last_error = Some(error);
+    }
+
+    /// Returns [`Error::TimedOut`] with `last_error`, taking the errors kept before it
+    /// by [`Self::keep_recent_errors()`].
+    pub(crate) fn timed_out(&mut self, last_error: E) -> Error<E> {
+        Error::TimedOut(TimeoutError {
+            last_error,
+            recent_errors: self
+                .recent_errors
+                .as_mut()
+                .map_or_else(Vec::new, |recent| recent.errors.drain(..).collect()),
+        })
+    }
+
+    /// Map the error type of this instance, preserving the state of the backoff.
+    ///
+    /// The error from the previous attempt, if any, is mapped using `map`.
+    /// This mirrors [`Error::map()`].
+    ///
+    /// The functions set by [`Self::on_zero_attempts()`], [`Self::on_failed_attempt()`]
+    /// (or its async equivalent), [`Self::fatal_on()`], [`Self::distinct_errors_by()`]
+    /// and [`Self::keep_recent_errors()`] cannot be mapped, and so are cleared, along with
+    /// any errors already kept or counted as distinct; set them again on the returned instance
+    /// if required.
+    ///
+    /// Note that clearing [`Self::on_zero_attempts()`] changes what happens if the deadline
+    /// has elapsed before the first attempt: if [`Options::allow_zero_attempts()`] is set,
+    /// skipping the attempt panics as there is no error to return; otherwise, zero attempts
+    /// are no longer allowed and the attempt is made.
+    pub fn map_err<E2>(self, map: impl FnOnce(E) -> E2) -> EaseOff<E2> {
+        // At most one is set, as `record_failed_attempt()` clears `not_ready_error`.
+        let (last_error, not_ready_error) = match (self.last_error, self.not_ready_error) {
+            (Some(e), _) => (Some(map(e)), None),
+            (None, Some(e)) => (None, Some(map(e))),
+            (None, None) => (None, None),
+        };
+
+        EaseOff {
+            core: self.core,
+            started_at: self.started_at,
+            deadline: self.deadline,
+            paused_at: self.paused_at,
+            paused_for: self.paused_for,
+            num_attempts: self.num_attempts,
+            attempt_was_delayed: self.attempt_was_delayed,
+            total_slept: self.total_slept,
+            delay_summary: self.delay_summary,
+            history_limit: self.history_limit,
+            realized_delays: self.realized_delays,
+            last_attempt_duration: self.last_attempt_duration,
+            total_attempt_duration: self.total_attempt_duration,
+            consecutive_timeouts: self.consecutive_timeouts,
+            last_error,
+            not_ready_error,
+            resumed: self.resumed,
+            next_retry_at: self.next_retry_at,
+            next_delay: self.next_delay,
+            make_zero_attempts_error: None,
+            limiter: self.limiter,
+            on_failed_attempt: None,
+            fatal_on: None,
+            distinct_errors_by: None,
+            distinct_errors: Vec::new(),
+            recent_errors: None,
+            on_max_delay_reached: self.on_max_delay_reached,
+            on_soft_deadline: self.on_soft_deadline,
+            #[cfg(feature = "governor")]
+            governor: self.governor,
+            #[cfg(feature = "log")]
+            log_fields: self.log_fields,
+            #[cfg(feature = "futures")]
+            pending_cleanup: self.pending_cleanup,
+        }
+    }
+
+    /// Returns the error for skipping the next attempt if the deadline has elapsed
+    /// and zero attempts are allowed; see [`Options::allow_zero_attempts()`].
+    fn zero_attempts_error(&self, now: Instant) -> Option<Error<E>> {
+        if !self.core.options().allow_zero_attempts && self.make_zero_attempts_error.is_none() {
+            return None;
+        }
+
+        let deadline = self.deadline_at(now)?;
+
+        if now < deadline {
+            return None;
+        }
+
+        let Some(make_error) = self.make_zero_attempts_error else {
+            panic!(
+                "the deadline elapsed before the first attempt, which `allow_zero_attempts` \
+                 skips, but there is no error to return; see `EaseOff::on_zero_attempts()`"
+            );
+        };
+
+        Some(Error::TimedOut(TimeoutError {
+            last_error: make_error(),
+            recent_errors: Vec::new(),
+        }))
+    }
+
+    /// Returns `true` if the previous attempt failed or was not ready.
+    fn is_backing_off(&self) -> bool {
+        self.last_error.is_some() || self.not_ready_error.is_some()
+    }
+
+    /// Record that the previous attempt was not ready, calling `make_error` for the error
+    /// to return if the deadline elapses, unless an attempt has already failed.
+    fn record_not_ready(&mut self, make_error: impl FnOnce() -> E) {
+        if self.last_error.is_none() {
+            self.not_ready_error.get_or_insert_with(make_error);
+        }
+    }
+
+    /// Take the error to return if the operation is not retried.
+    ///
+    /// Must only be called if [`Self::is_backing_off()`].
+    fn take_backoff_error(&mut self) -> E {
+        self.last_error
+            .take()
+            .or_else(|| self.not_ready_error.take())
+            .expect("BUG: backing off without an error")
+    }
+
+    /// Returns the time to sleep until before the next attempt, if any,
+    /// or the error to return instead of making it.
+    pub(crate) fn next_retry_at_from(&mut self, now: Instant) -> Result<Option<Instant>, Error<E>> {
+        // Only acquired if jitter is actually applied.
+        let mut rng = rng::LazyRng::new();
+
+        // Set again below if an attempt will be made after a sleep.
+        self.attempt_was_delayed = false;
+
+        self.check_soft_deadline(now);
+
+        if !self.is_backing_off() {
+            let resumed = std::mem::take(&mut self.resumed);
+
+            if self.core.options().reset_on_success && !resumed {
+                self.num_attempts = Saturating(0);
+                self.total_slept = Duration::ZERO;
+                self.distinct_errors.clear();
+            }
+
+            if let Some(e) = self.zero_attempts_error(now) {
+                return Err(e);
+            }
+
+            let retry_at = cmp::max(
+                self.core
+                    .nth_retry_at(0, now, None, &mut rng)
+                    .expect("passed `None` for deadline, should not be `Err`"),
+                self.next_retry_at.take(),
+            );
+
+            return Ok(self.record_delay(retry_at, now));
+        }
+
+        let attempt_num = self.num_attempts.0;
+        // `num_attempts` is `Saturating<u32>` so we don't have to worry about overflow.
+        self.num_attempts += 1;
+
+        let retry_at = self.backoff_retry_at(attempt_num, now, &mut rng);
+        let delay_overridden = self.next_delay.take().is_some();
+
+        let Ok(retry_at) = retry_at else {
+            let last_error = self.take_backoff_error();
+            return Err(self.timed_out(last_error));
+        };
+
+        if let Some(limiter) = &self.limiter {
+            if !limiter.try_acquire() {
+                return Err(Error::RateLimited(self.take_backoff_error()));
+            }
+        }
+
+        if !delay_overridden
+            && self.on_max_delay_reached.is_some()
+            && self
+                .core
+                .nth_base_delay(attempt_num)
+                .is_some_and(|base| base.clamped_to_max)
+        {
+            if let Some(OnMaxDelayReached(on_max_delay_reached)) = self.on_max_delay_reached.take()
+            {
+                on_max_delay_reached();
+            }
+        }
+
+        let retry_at = cmp::max(retry_at, self.next_retry_at.take());
+
+        Ok(self.record_delay(retry_at, now))
+    }
+
+    /// Check the limiter set by [`Self::with_governor()`] for the attempt about to be made,
+    /// taking capacity for it if allowed.
+    ///
+    /// If denied, returns the time to check again at, or the error to return if that is after
+    /// the deadline. Must be called after sleeping until the time from `next_retry_at_from()`.
+    #[cfg(feature = "governor")]
+    pub(crate) fn governor_retry_at(&mut self, now: Instant) -> Result<Option<Instant>, Error<E>> {
+        let Some(GovernorCheck(check)) = &self.governor else {
+            return Ok(None);
+        };
+
+        let Some(retry_at) = check(now) else {
+            return Ok(None);
+        };
+
+        // The first attempt is always made, like the backoff.
+        if self.is_backing_off()
+            && self
+                .deadline_at(now)
+                .is_some_and(|deadline| retry_at > deadline)
+        {
+            let last_error = self.take_backoff_error();
+            return Err(self.timed_out(last_error));
+        }
+
+        self.attempt_was_delayed = true;
+        self.total_slept = self
+            .total_slept
+            .saturating_add(retry_at.saturating_duration_since(now));
+
+        Ok(Some(retry_at))
+    }
+
+    /// Returns the time of the `n`th retry, or the time set by [`PollAfter::Pending`] instead.
+    fn backoff_retry_at(
+        &self,
+        n: u32,
+        now: Instant,
+        rng: &mut (impl rand::Rng + ?Sized),
+    ) -> Result<Option<Instant>, RetryAfterDeadline> {
+        let deadline = self.deadline_at(now);
+
+        if self.core.options().deadline_excludes_sleep {
+            // The sleep before this attempt doesn't count toward the deadline either,
+            // so only check that the deadline hasn't elapsed yet.
+            let retry_at = self.backoff_retry_at_before(n, now, None, rng)?;
+
+            return match deadline {
+                Some(deadline) if now > deadline => Err(RetryAfterDeadline {
+                    n,
+                    retry_at: retry_at.unwrap_or(now),
+                    deadline,
+                }),
+                _ => Ok(retry_at),
+            };
+        }
+
+        self.backoff_retry_at_before(n, now, deadline, rng)
+    }
+
+    fn backoff_retry_at_before(
+        &self,
+        n: u32,
+        now: Instant,
+        deadline: Option<Instant>,
+        rng: &mut (impl rand::Rng + ?Sized),
+    ) -> Result<Option<Instant>, RetryAfterDeadline> {
+        let Some(delay) = self.next_delay else {
+            return self.core.nth_retry_at(n, now, deadline, rng);
+        };
+
+        let retry_at = clock::saturating_add(now, cmp::min(delay, self.core.options().max_delay));
+
+        match deadline {
+            Some(deadline) if retry_at > deadline => Err(RetryAfterDeadline {
+                n,
+                retry_at,
+                deadline,
+            }),
+            _ => Ok(Some(retry_at)),
+        }
+    }
+
+    /// Must be called after `next_retry_at_from()` for the attempt about to be made.
+    pub(crate) fn attempt_context(&self) -> AttemptContext {
+        let summary = self.summary();
+
+        AttemptContext {
+            attempt_number: self.num_attempts.0.saturating_add(1),
+            elapsed: summary.elapsed,
+            remaining: summary.remaining,
+        }
+    }
+
+    /// Returns `true` if the limit set by [`Options::max_distinct_errors()`] has been reached.
+    fn record_distinct_error(&mut self, error: &E) -> bool {
+        let (Some(max), Some(ErrorKey(key))) = (
+            self.core.options().max_distinct_errors,
+            &self.distinct_errors_by,
+        ) else {
+            return false;
+        };
+
+        let max = cmp::max(max, 1) as usize;
+        let key = key(error);
+
+        if self.distinct_errors.len() < max && !self.distinct_errors.contains(&key) {
+            self.distinct_errors.push(key);
+        }
+
+        self.distinct_errors.len() >= max
+    }
+
+    fn record_attempt_duration(&mut self, started_at: Instant) {
+        let duration = clock::now().saturating_duration_since(started_at);
+
+        self.last_attempt_duration = Some(duration);
+        self.total_attempt_duration = self.total_attempt_duration.saturating_add(duration);
+    }
+
+    fn record_delay(&mut self, retry_at: Option<Instant>, now: Instant) -> Option<Instant> {
+        let delay = retry_at.map_or(Duration::ZERO, |retry_at| {
+            retry_at.saturating_duration_since(now)
+        });
+
+        self.attempt_was_delayed = !delay.is_zero();
+        self.total_slept = self.total_slept.saturating_add(delay);
+
+        if self.attempt_was_delayed {
+            self.delay_summary.record(delay);
+
+            let limit = self
+                .core
+                .options()
+                .history_capacity
+                .map_or(self.history_limit, |cap| cmp::min(cap, self.history_limit));
+
+            if limit > 0 {
+                while self.realized_delays.len() >= limit {
+                    self.realized_delays.pop_front();
+                }
+
+                self.realized_delays.push_back(delay);
+            }
+
+            #[cfg(feature = "log")]
+            self.log(
+                log::Level::Trace,
+                format_args!(
+                    "ease-off: sleeping {delay:?} before attempt {}",
+                    self.num_attempts.0.saturating_add(1)
+                ),
+            );
+        }
+
+        retry_at
+    }
+
+    /// Log an event with the fields set by [`Self::with_fields()`].
+    #[cfg(feature = "log")]
+    fn log(&self, level: log::Level, args: fmt::Arguments<'_>) {
+        if level > log::STATIC_MAX_LEVEL || level > log::max_level() {
+            return;
+        }
+
+        log::logger().log(
+            &log::Record::builder()
+                .args(args)
+                .level(level)
+                .target(module_path!())
+                .module_path_static(Some(module_path!()))
+                .key_values(&self.log_fields)
+                .build(),
+        );
+    }
+
+    /// Sleep until the next attempt is due, then make it by calling `op`.
+    ///
+    /// The result, or the error if no attempt is made, is passed to `wrap`.
+    fn attempt_blocking<R, T>(
+        &mut self,
+        now: Instant,
+        op: impl FnOnce(&Self) -> Result<R, Error<E>>,
+        wrap: impl FnOnce(&mut Self, Result<R, Error<E>>) -> ResultWrapper<'_, T, E>,
+    ) -> ResultWrapper<'_, T, E> {
+        let res = self.next_retry_at_from(now).and_then(|retry_at| {
+            if let Some(instant) = retry_at {
+                blocking_sleep_until(instant);
+            }
+
+            #[cfg(feature = "governor")]
+            while let Some(instant) = self.governor_retry_at(clock::now())? {
+                blocking_sleep_until(instant);
+            }
+
+            let started_at = clock::now();
+            let res = op(self);
+            self.record_attempt_duration(started_at);
+            res
+        });
+
+        wrap(self, res)
+    }
+
+    fn wrap_result<T>(&mut self, result: Result<T, Error<E>>) -> ResultWrapper<'_, T, E> {
+        self.wrap_option_result(result.map(Some))
+    }
+
+    fn wrap_poll_after_result<T>(
+        &mut self,
+        result: Result<PollAfter<T>, Error<E>>,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E> {
+        let result = result.map(|poll| match poll {
+            PollAfter::Ready(value) => Some(value),
+            PollAfter::Pending(delay) => {
+                self.next_delay = Some(delay);
+                None
+            }
+        });
+
+        self.wrap_poll_result(result, make_error)
+    }
+
+    fn wrap_decision_result<T>(
+        &mut self,
+        outcome: Result<OpOutcome<T, E>, Error<E>>,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E> {
+        let (result, verdict) = match outcome {
+            Ok(OpOutcome::Done(value)) => (Ok(Some(value)), None),
+            Ok(OpOutcome::Retry(None)) => (Ok(None), None),
+            Ok(OpOutcome::Retry(Some(e))) => {
+                (Err(Error::MaybeRetryable(e)), Some(Fatality::Retryable))
+            }
+            Ok(OpOutcome::Fatal(e)) => (Err(Error::Fatal(e)), Some(Fatality::Fatal)),
+            // No attempt was made.
+            Err(e) => {
+                let mut wrapper = self.wrap_result(Err(e));
+                wrapper.verdict = Some(Fatality::Fatal);
+                return wrapper;
+            }
+        };
+
+        let mut wrapper = self.wrap_poll_result(result, make_error);
+        // `fatal_on` and `max_distinct_errors` take precedence.
+        wrapper.verdict = wrapper.verdict.or(verdict);
+        wrapper
+    }
+
+    fn wrap_poll_result<T>(
+        &mut self,
+        result: Result<Option<T>, Error<E>>,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E> {
+        if let Ok(None) = result {
+            self.record_not_ready(make_error);
+        }
+
+        self.wrap_option_result(result)
+    }
+
+    fn wrap_option_result<T>(
+        &mut self,
+        result: Result<Option<T>, Error<E>>,
+    ) -> ResultWrapper<'_, T, E> {
+        let (result, verdict) = match (result, &self.fatal_on) {
+            (Err(Error::MaybeRetryable(e)), Some(FatalOn(fatal_on))) if fatal_on(&e) => {
+                (Err(Error::Fatal(e)), Some(Fatality::Fatal))
+            }
+            (result, _) => (result, None),
+        };
+
+        let (result, verdict) = match result {
+            Err(Error::MaybeRetryable(e)) if self.record_distinct_error(&e) => {
+                (Err(Error::TooManyDistinctErrors(e)), Some(Fatality::Fatal))
+            }
+            result => (result, verdict),
+        };
+
+        ResultWrapper {
+            result,
+            attempt: self.num_attempts.0.saturating_add(1),
+            ease_off: self,
+            verdict,
+            timeout_checked: false,
+        }
+    }
+}
+
+impl<E> EaseOff<E> {
+    /// Attempt a blocking operation.
+    ///
+    /// If the operation previously failed, sleeps for the prescribed backoff period
+    /// using [`std::thread::sleep()`].
+    ///
+    /// ### Note: Behavior at Deadline
+    /// Most blocking operations cannot be cancelled once begun, so the [deadline][Self::deadline],
+    /// if set, is only checked *before* attempting the operation.
+    ///
+    /// Generally, the only kinds of blocking operations that support cancellation
+    /// take an explicit timeout (such as setting a read timeout on a socket).
+    ///
+    /// If you want a blocking operation to be cancelled immediately once the deadline elapses,
+    /// consult the documentation for the API you are calling to see if timeouts are supported,
+    /// and if so, how to configure them.
+    pub fn try_blocking<T>(
+        &mut self,
+        op: impl FnOnce() -> Result<T, E>,
+    ) -> ResultWrapper<'_, T, E> {
+        self.attempt_blocking(
+            clock::now(),
+            |_| op().map_err(Error::MaybeRetryable),
+            Self::wrap_result,
+        )
+    }
+
+    /// Like [`Self::try_blocking()`], but passes an [`AttemptContext`] describing the
+    /// current attempt to the operation.
+    ///
+    /// This lets the operation adapt to where it is in the retry sequence, e.g. to widen
+    /// a timeout on later attempts or switch to a fallback endpoint, without capturing
+    /// the `EaseOff`. The context is taken after the backoff sleep, just before the
+    /// operation is invoked.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .start_timeout(Duration::from_secs(10));
+    ///
+    /// let endpoint = loop {
+    ///     let Some(endpoint) = ease_off
+    ///         .try_blocking_ctx(|ctx| {
+    ///             assert!(ctx.remaining.is_some());
+    ///
+    ///             // Fail over after two attempts at the primary.
+    ///             match ctx.attempt_number {
+    ///                 1 | 2 => Err("primary unavailable"),
+    ///                 _ => Ok("secondary"),
+    ///             }
+    ///         })
+    ///         .or_retry_if(|_e| true)?
+    ///     else {
+    ///         continue;
+    ///     };
+    ///
+    ///     break endpoint;
+    /// };
+    ///
+    /// assert_eq!(endpoint, "secondary");
+    /// # Ok::<_, &str>(())
+    /// ```
+    pub fn try_blocking_ctx<T>(
+        &mut self,
+        op: impl FnOnce(AttemptContext) -> Result<T, E>,
+    ) -> ResultWrapper<'_, T, E> {
+        self.attempt_blocking(
+            clock::now(),
+            |this| op(this.attempt_context()).map_err(Error::MaybeRetryable),
+            Self::wrap_result,
+        )
+    }
+
+    /// Like [`Self::try_blocking()`], but using `now` as the current time for this step
+    /// instead of reading the clock.
+    ///
+    /// This is intended for simulation and testing, e.g. replaying historical event
+    /// timestamps through the backoff logic. `now` is used to check the
+    /// [deadline][Self::deadline()] and to schedule the next attempt.
+    ///
+    /// The sleep before the attempt still waits until the scheduled instant
+    /// on the real clock. When replaying timestamps from the past, the scheduled instants
+    /// are usually in the past as well, so no sleep occurs. For full control over sleeping,
+    /// use [`Self::try_async_at()`] with a custom sleeper (requires the `futures` feature).
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::{Error, Options};
+    ///
+    /// // Replay an hour-old outage without waiting.
+    /// let started_at = Instant::now() - Duration::from_secs(3600);
+    ///
+    /// let mut ease_off = Options::new().start_deadline(started_at + Duration::from_secs(10));
+    ///
+    /// let mut now = started_at;
+    /// let mut attempts = 0;
+    ///
+    /// let result = loop {
+    ///     let result = ease_off
+    ///         .try_blocking_at(now, || {
+    ///             attempts += 1;
+    ///             Err::<(), _>("unavailable")
+    ///         })
+    ///         // Anything but `Error::TimedOut`.
+    ///         .or_retry_if(|e| matches!(e, Error::MaybeRetryable(_)));
+    ///
+    ///     match result {
+    ///         Ok(None) => now += Duration::from_secs(1),
+    ///         result => break result,
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(result, Err("unavailable"));
+    /// assert!(attempts > 1 && attempts <= 11);
+    /// ```
+    pub fn try_blocking_at<T>(
+        &mut self,
+        now: Instant,
+        op: impl FnOnce() -> Result<T, E>,
+    ) -> ResultWrapper<'_, T, E> {
+        self.attempt_blocking(
+            now,
+            |_| op().map_err(Error::MaybeRetryable),
+            Self::wrap_result,
+        )
+    }
+
+    /// Attempt a blocking operation which may not be ready yet.
+    ///
+    /// This is for polling APIs, where the operation returning `Ok(None)` means
+    /// "not ready, check again later" rather than a failure, such as waiting for a resource
+    /// to be provisioned.
+    ///
+    /// * `Ok(Some(_))` completes the operation, as `Ok(_)` does for [`Self::try_blocking()`].
+    /// * `Ok(None)` causes `.or_retry()` (and friends) to return `Ok(None)` without recording
+    ///   an error or checking retryability, and the next attempt waits for the next backoff
+    ///   period.
+    /// * `Err(_)` is checked for retryability as normal.
+    ///
+    /// A not-ready result advances the attempt counter ([`Self::num_attempts()`])
+    /// the same as a retryable error, so the delay between polls grows exponentially
+    /// until the operation completes; the counter is reset once it does
+    /// (see [`Options::reset_on_success()`]).
+    ///
+    /// If the [deadline][Self::deadline] elapses while the operation is not ready,
+    /// [`Error::TimedOut`] is returned with the error from the most recent failed attempt,
+    /// or the error returned by `make_error` if no attempt has failed.
+    /// `make_error` is called when the operation is first not ready, unless an attempt
+    /// has already failed, so the timeout always has an error to return.
+    ///
+    /// See [`Self::try_blocking()`] for notes on behavior at the deadline.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let mut polls = 0;
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+    ///
+    /// let value = loop {
+    ///     let Some(value) = ease_off
+    ///         .try_blocking_poll(
+    ///             || -> Result<Option<&str>, String> {
+    ///                 polls += 1;
+    ///                 Ok((polls == 3).then_some("ready!"))
+    ///             },
+    ///             || "timed out waiting for resource".to_string(),
+    ///         )
+    ///         .or_retry_if(|_e| false)?
+    ///     else {
+    ///         continue;
+    ///     };
+    ///
+    ///     break value;
+    /// };
+    ///
+    /// assert_eq!(value, "ready!");
+    /// assert_eq!(polls, 3);
+    /// # Ok::<_, String>(())
+    /// ```
+    pub fn try_blocking_poll<T>(
+        &mut self,
+        op: impl FnOnce() -> Result<Option<T>, E>,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E> {
+        self.attempt_blocking(
+            clock::now(),
+            |_| op().map_err(Error::MaybeRetryable),
+            |this, res| this.wrap_poll_result(res, make_error),
+        )
+    }
+
+    /// Attempt a blocking operation which may not be ready yet,
+    /// and which says how long to wait before checking again.
+    ///
+    /// This is for APIs where even a successful response may carry backoff guidance,
+    /// e.g. `202 Accepted` with a hint of when to poll next.
+    ///
+    /// This behaves like [`Self::try_blocking_poll()`], except that a not-ready result,
+    /// [`PollAfter::Pending(delay)`][PollAfter::Pending], sets the delay before the next
+    /// attempt to `delay`, overriding the calculated backoff:
+    ///
+    /// * `delay` is capped at [`Options::max_delay()`].
+    /// * If the next attempt would be after the [deadline][Self::deadline],
+    ///   [`Error::TimedOut`] is returned, as for a calculated delay.
+    /// * The attempt counter advances as for any other not-ready result, so if a later attempt
+    ///   does not give a hint, the calculated backoff continues from there.
+    /// * A [`RetryLimiter`], if set, is still consulted.
+    ///
+    /// A delay of zero means the next attempt is made immediately.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{EaseOff, PollAfter};
+    ///
+    /// let mut polls = 0;
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+    ///
+    /// let value = loop {
+    ///     let Some(value) = ease_off
+    ///         .try_blocking_poll_after(
+    ///             || -> Result<_, String> {
+    ///                 polls += 1;
+    ///
+    ///                 if polls < 3 {
+    ///                     // The server says to check again in 5ms.
+    ///                     Ok(PollAfter::Pending(Duration::from_millis(5)))
+    ///                 } else {
+    ///                     Ok(PollAfter::Ready("done"))
+    ///                 }
+    ///             },
+    ///             || "timed out waiting for the job".to_string(),
+    ///         )
+    ///         .or_retry_if(|_e| false)?
+    ///     else {
+    ///         // The next attempt waits for the hint rather than the calculated backoff.
+    ///         assert!(ease_off.next_retry_at_peek().unwrap().is_some());
+    ///         continue;
+    ///     };
+    ///
+    ///     break value;
+    /// };
+    ///
+    /// assert_eq!(value, "done");
+    /// assert_eq!(polls, 3);
+    /// # Ok::<_, String>(())
+    /// ```
+    pub fn try_blocking_poll_after<T>(
+        &mut self,
+        op: impl FnOnce() -> Result<PollAfter<T>, E>,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E> {
+        self.attempt_blocking(
+            clock::now(),
+            |_| op().map_err(Error::MaybeRetryable),
+            |this, res| this.wrap_poll_after_result(res, make_error),
+        )
+    }
+
+    /// Attempt a blocking operation which decides for itself whether it should be retried.
+    ///
+    /// This is for operations whose retryability depends on knowledge local to the operation
+    /// rather than on the error itself, so [`RetryableError`] or a closure over the error
+    /// cannot decide it:
+    ///
+    /// * [`OpOutcome::Done`] completes the operation, as `Ok(_)` does for [`Self::try_blocking()`].
+    /// * [`OpOutcome::Retry(Some(e))`][OpOutcome::Retry] records `e` as a retryable error,
+    ///   to be returned if the [deadline][Self::deadline] elapses.
+    /// * [`OpOutcome::Retry(None)`][OpOutcome::Retry] retries without recording an error,
+    ///   the same as a not-ready result from [`Self::try_blocking_poll()`].
+    /// * [`OpOutcome::Fatal(e)`][OpOutcome::Fatal] returns `e` without retrying.
+    ///
+    /// The decision is final: `.or_retry()` and friends do not check the error again,
+    /// so the closure passed to `.or_retry_if()` is never called and
+    /// `.or_retry_if(|_e| false)` is sufficient. The exceptions are [`Self::fatal_on()`]
+    /// and [`Options::max_distinct_errors()`], which may still make a `Retry(Some(e))` fatal.
+    ///
+    /// ### Note: Retrying Without an Error
+    /// If the [deadline][Self::deadline] elapses, [`Error::TimedOut`] is returned with the error
+    /// from the most recent `Retry(Some(e))`, even if later attempts returned `Retry(None)`,
+    /// or the error returned by `make_error` if no attempt returned an error.
+    /// Either way, the timeout is returned as `Err`, with no chance to retry it.
+    ///
+    /// See [`Self::try_blocking()`] for notes on behavior at the deadline.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{EaseOff, OpOutcome};
+    ///
+    /// let mut attempts = 0;
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+    ///
+    /// let value = loop {
+    ///     let Some(value) = ease_off
+    ///         .try_blocking_decision(
+    ///             || {
+    ///                 attempts += 1;
+    ///
+    ///                 match attempts {
+    ///                     // Still warming up; not an error.
+    ///                     1 => OpOutcome::Retry(None),
+    ///                     2 => OpOutcome::Retry(Some("cache miss")),
+    ///                     _ => OpOutcome::Done("hit"),
+    ///                 }
+    ///             },
+    ///             || "timed out before the first attempt",
+    ///         )
+    ///         .or_retry_if(|_e| false)?
+    ///     else {
+    ///         continue;
+    ///     };
+    ///
+    ///     break value;
+    /// };
+    ///
+    /// assert_eq!(value, "hit");
+    /// assert_eq!(attempts, 3);
+    ///
+    /// let result = EaseOff::start_unlimited()
+    ///     .try_blocking_decision(|| OpOutcome::<(), _>::Fatal("bad request"), || unreachable!())
+    ///     .or_retry_if(|_e| true);
+    ///
+    /// assert_eq!(result, Err("bad request"));
+    /// # Ok::<_, &str>(())
+    /// ```
+    pub fn try_blocking_decision<T>(
+        &mut self,
+        op: impl FnOnce() -> OpOutcome<T, E>,
+        make_error: impl FnOnce() -> E,
+    ) -> ResultWrapper<'_, T, E> {
+        self.attempt_blocking(
+            clock::now(),
+            |_| Ok(op()),
+            |this, outcome| this.wrap_decision_result(outcome, make_error),
+        )
+    }
+}
+
+/// Wrapper for [`Result`] returned from methods on [`EaseOff`].
+///
+/// Retryable errors will be stored in the `EaseOff` to be returned on the next attempt
+/// if the [deadline][EaseOff::deadline] has passed.
+#[must_use = "`.or_retry()` or `.or_retry_if()` must be called"]
+pub struct ResultWrapper<'a, T, E: 'a> {
+    // `Ok(None)` indicates the operation was not ready.
+    result: Result<Option<T>, Error<E>>,
+    // 1-based, like `AttemptContext::attempt_number`.
+    attempt: u32,
+    ease_off: &'a mut EaseOff<E>,
+    verdict: Option<Fatality>,
+    timeout_checked: bool,
+}
+
+impl<'a, T, E: 'a> ResultWrapper<'a, T, E> {
+    /// Returns the number of the attempt that produced this result, starting from `1`.
+    ///
+    /// This is the same as [`AttemptContext::attempt_number`] and the number in log messages,
+    /// provided here for convenience as the `EaseOff` is borrowed by this wrapper.
+    ///
+    /// If the [deadline][EaseOff::deadline()] elapsed before the attempt could be made,
+    /// this is the number of the attempt that was skipped.
+    #[inline(always)]
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Convert a [`TimeoutError`], if applicable, to another [`Error`] variant.
+    ///
+    /// May be used to convert a timeout error into [`Error::MaybeRetryable`].
+    ///
+    /// If not otherwise handled, `.or_retry()` and `.or_retry_if()` will return the error.
+    pub fn on_timeout(
+        self,
+        on_timeout: impl FnOnce(TimeoutError<E>) -> Error<E>,
+    ) -> ResultWrapper<'a, T, E> {
+        Self {
+            result: self.result.map_err(|e| e.on_timeout(on_timeout)),
+            ..self
+        }
+    }
+
+    /// Inspect the error if the operation failed.
+    ///
+    /// This could also be [`Error::TimedOut`] containing an error from a previous iteration.
+    pub fn inspect_err(self, inspect_err: impl FnOnce(&Error<E>)) -> Self {
+        Self {
+            result: self.result.inspect_err(inspect_err),
+            ..self
+        }
+    }
+
+    /// Classify the error, if the operation failed, and cache the verdict.
+    ///
+    /// Useful if classifying the error is expensive and it would otherwise be checked
+    /// multiple times in the same chain of calls.
+    ///
+    /// The closure is called at most once per attempt; if a verdict has already been cached
+    /// by a previous call to this method, it is not called again.
+    ///
+    /// If the verdict is [`Fatality::Fatal`], an [`Error::MaybeRetryable`] is converted
+    /// to [`Error::Fatal`], so calls to [`.inspect_err()`][Self::inspect_err] _after_ this one
+    /// will see the classified error while calls _before_ this one see the unclassified error.
+    ///
+    /// `.or_retry()` and `.or_retry_if()` use the cached verdict instead of checking
+    /// the error again. `.or_retry_with()` returns the error immediately
+    /// if the verdict is [`Fatality::Fatal`]; otherwise its closure is still invoked
+    /// to determine the next retry time.
+    pub fn classify_once(mut self, classify: impl FnOnce(&Error<E>) -> Fatality) -> Self {
+        let Err(e) = &self.result else {
+            return self;
+        };
+
+        let verdict = *self.verdict.get_or_insert_with(|| classify(e));
+
+        if verdict == Fatality::Fatal {
+            self.result = self.result.map_err(|e| match e {
+                Error::MaybeRetryable(e) => Error::Fatal(e),
+                other => other,
+            });
+        }
+
+        self
+    }
+
+    /// Count a timeout towards [`Options::max_consecutive_timeouts()`] if the operation failed
+    /// and `is_timeout` returns `true` for the error.
+    ///
+    /// Any other outcome resets the count. If the count reaches the maximum,
+    /// the error is converted to [`Error::TooManyTimeouts`] and treated as fatal
+    /// by `.or_retry()` and its variants.
+    ///
+    /// [`.or_retry()`][Self::or_retry] calls this with [`RetryableError::is_timeout()`],
+    /// so it only needs to be called explicitly with `.or_retry_if()` or `.or_retry_with()`.
+    /// The closure is called at most once per attempt; subsequent calls have no effect.
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use std::time::Duration;
+    /// use ease_off::{Error, Options};
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .max_consecutive_timeouts(3)
+    ///     .start_timeout(Duration::from_secs(60));
+    ///
+    /// let mut attempts = 0;
+    ///
+    /// let result = loop {
+    ///     let result = ease_off
+    ///         .try_blocking(|| -> Result<(), _> {
+    ///             attempts += 1;
+    ///             Err(io::Error::from(io::ErrorKind::TimedOut))
+    ///         })
+    ///         .timeout_if(|e| e.kind() == io::ErrorKind::TimedOut)
+    ///         .inspect_err(|e| {
+    ///             if attempts == 3 {
+    ///                 assert!(matches!(e, Error::TooManyTimeouts(_)));
+    ///             }
+    ///         })
+    ///         .or_retry_if(|_e| true);
+    ///
+    ///     match result {
+    ///         Ok(Some(())) => break Ok(()),
+    ///         Ok(None) => continue,
+    ///         Err(e) => break Err(e),
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(result.unwrap_err().kind(), io::ErrorKind::TimedOut);
+    /// assert_eq!(attempts, 3);
+    /// assert_eq!(ease_off.consecutive_timeouts(), 3);
+    /// ```
+    pub fn timeout_if(mut self, is_timeout: impl FnOnce(&E) -> bool) -> Self {
+        if std::mem::replace(&mut self.timeout_checked, true) {
+            return self;
+        }
+
+        let Err(Error::MaybeRetryable(e)) = &self.result else {
+            self.ease_off.consecutive_timeouts = 0;
+            return self;
+        };
+
+        if !is_timeout(e) {
+            self.ease_off.consecutive_timeouts = 0;
+            return self;
+        }
+
+        let ease_off = &mut *self.ease_off;
+        ease_off.consecutive_timeouts = ease_off.consecutive_timeouts.saturating_add(1);
+
+        let Some(max) = ease_off.core.options().max_consecutive_timeouts else {
+            return self;
+        };
+
+        if ease_off.consecutive_timeouts >= max {
+            self.verdict = Some(Fatality::Fatal);
+            self.result = self.result.map_err(|e| match e {
+                Error::MaybeRetryable(e) => Error::TooManyTimeouts(e),
+                other => other,
+            });
+        }
+
+        self
+    }
+
+    /// Check the result, testing the error for retryability using [`RetryableError`] if applicable.
+    ///
+    /// If the operation was successful, `Ok(Some(_))` is returned.
+    ///
+    /// If the operation failed but [`RetryableError::can_retry()`] returned `true`,
+    /// `Ok(None)` is returned and the error is stored in the [`EaseOff`] instance for the next
+    /// iteration.
+    ///
+    /// If the error was determined to be fatal or the [deadline][EaseOff::deadline()] has elapsed,
+    /// `Err` is returned.
+    ///
+    /// Timeouts are counted towards [`Options::max_consecutive_timeouts()`]
+    /// using [`RetryableError::is_timeout()`]; see [`Self::timeout_if()`].
+    pub fn or_retry(self) -> Result<Option<T>, E>
+    where
+        E: RetryableError,
+    {
+        self.timeout_if(RetryableError::is_timeout)
+            .or_retry_if(RetryableError::can_retry)
+    }
+
+    /// Check the result, testing the error for retryability using the given closure if applicable.
+    ///
+    /// The closure will be invoked with either the error from the current attempt,
+    /// or a previous error if the [deadline][EaseOff::deadline()] has elapsed
+    /// (this is indicated by the [`Error::TimedOut`] variant).
+    ///
+    /// If the operation was successful, `Ok(Some(_))` is returned.
+    ///
+    /// If the operation failed but the given closure returns `true`,
+    /// `Ok(None)` is returned and the error is stored in the [`EaseOff`] instance for the next
+    /// iteration.
+    ///
+    /// If the error was determined to be fatal, `Err` is returned.
+    pub fn or_retry_if(self, can_retry: impl FnOnce(&Error<E>) -> bool) -> Result<Option<T>, E> {
+        let verdict = self.verdict;
+
+        self.or_retry_with(|e| {
+            let can_retry = match verdict {
+                Some(verdict) => verdict == Fatality::Retryable,
+                None => can_retry(e),
+            };
+
+            if can_retry {
+                ControlFlow::Continue(None)
+            } else {
+                ControlFlow::Break(())
+            }
+        })
+    }
+
+    /// Check the result, keeping a successful value but backing off before the next attempt
+    /// if `should_retry` returns `true` for it.
+    ///
+    /// This is for operations that return provisional or partial results,
+    /// e.g. polling a job which reports its progress so far.
+    ///
+    /// Errors are tested for retryability using [`RetryableError`], as with
+    /// [`.or_retry()`][Self::or_retry].
+    ///
+    /// The return value signals the following to the caller's loop:
+    ///
+    /// * `Ok(Some(value))` if the operation was successful, whatever `should_retry` returned.
+    ///   * If `should_retry` returned `true`, the next attempt waits for the next backoff period,
+    ///     the same as if the operation was [not ready][EaseOff::try_blocking_poll()];
+    ///     the caller should use the value and continue the loop.
+    ///   * Otherwise, the backoff is reset as for any other success;
+    ///     the caller should break out of the loop.
+    /// * `Ok(None)` if the operation failed with a retryable error; the caller should continue.
+    /// * `Err` if the error was fatal or the [deadline][EaseOff::deadline()] has elapsed.
+    ///
+    /// If the deadline elapses while backing off after a provisional value, the next attempt
+    /// returns [`Error::TimedOut`] with the error from the most recent failed attempt,
+    /// or the error returned by `make_error` if no attempt has failed.
+    /// `make_error` is called for the first provisional value, unless an attempt has already
+    /// failed.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{EaseOff, RetryableError};
+    ///
+    /// #[derive(Debug)]
+    /// struct JobError;
+    ///
+    /// impl RetryableError for JobError {
+    ///     fn can_retry(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// // Pretend progress reports from a long-running job.
+    /// let mut reports = vec![Ok(100), Ok(60), Err(JobError), Ok(20)];
+    /// let mut seen = Vec::new();
+    ///
+    /// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+    ///
+    /// let done = loop {
+    ///     let Some(progress) = ease_off
+    ///         .try_blocking(|| reports.pop().unwrap())
+    ///         .keep_and_retry_if(|&progress| progress < 100, || JobError)?
+    ///     else {
+    ///         continue;
+    ///     };
+    ///
+    ///     seen.push(progress);
+    ///
+    ///     if progress == 100 {
+    ///         break progress;
+    ///     }
+    /// };
+    ///
+    /// assert_eq!(done, 100);
+    /// assert_eq!(seen, [20, 60, 100]);
+    /// # Ok::<_, JobError>(())
+    /// ```
+    pub fn keep_and_retry_if(
+        self,
+        should_retry: impl FnOnce(&T) -> bool,
+        make_error: impl FnOnce() -> E,
+    ) -> Result<Option<T>, E>
+    where
+        E: RetryableError,
+    {
+        match self.result {
+            Ok(Some(value)) if should_retry(&value) => {
+                self.ease_off.record_not_ready(make_error);
+                self.ease_off.next_retry_at = None;
+                Ok(Some(value))
+            }
+            result => ResultWrapper { result, ..self }.or_retry(),
+        }
+    }
+
+    /// Check the result, testing the error for retryability using the given closure if applicable.
+    ///
+    /// The closure will be invoked with either the error from the current attempt,
+    /// or a previous error if the [deadline][EaseOff::deadline()] has elapsed
+    /// (this is indicated by the [`Error::TimedOut`] variant).
+    ///
+    /// If the operation was successful, `Ok(Some(_))` is returned.
+    ///
+    /// If the operation failed but the given closure returns `ControlFlow::Continue`,
+    /// `Ok(None)` is returned and the error is stored in the [`EaseOff`] instance for the next
+    /// iteration.
+    ///
+    /// If the given error specifies a retry time,
+    /// the closure may return `Control::Continue(Some(retry_at))`
+    /// with the next attempt waiting until `retry_at` or the current backoff,
+    /// whichever is later.
+    ///
+    /// Otherwise, the closure should return `ControlFlow::Continue(None)` to use the
+    /// next backoff time.
+    ///
+    /// If the error is fatal, the closure should return `ControlFlow::Break(())`
+    /// and then `Err` is returned.
+    pub fn or_retry_with(
+        self,
+        should_retry: impl FnOnce(&Error<E>) -> ControlFlow<(), Option<Instant>>,
+    ) -> Result<Option<T>, E> {
+        match self.result {
+            Ok(Some(success)) => {
+                self.ease_off.last_error = None;
+                self.ease_off.not_ready_error = None;
+
+                if let Some(recent) = &mut self.ease_off.recent_errors {
+                    recent.errors.clear();
+                }
+
+                self.ease_off.next_retry_at = None;
+                Ok(Some(success))
+            }
+            // The error to time out with was recorded when the result was wrapped.
+            Ok(None) => {
+                self.ease_off.next_retry_at = None;
+                Ok(None)
+            }
+            Err(e) if self.verdict == Some(Fatality::Fatal) => Err(e.into_inner()),
+            Err(e) => match should_retry(&e) {
+                ControlFlow::Continue(next_retry_at) => {
+                    self.ease_off.record_failed_attempt(e.into_inner());
+                    self.ease_off.next_retry_at = next_retry_at;
+
+                    Ok(None)
+                }
+                ControlFlow::Break(()) => Err(e.into_inner()),
+            },
+        }
+    }
+}
+
+/// Trait which may be implemented for error types to enable code reuse with [`EaseOff`].
+///
+/// Also implemented for references, [`Box`] and [`Arc`] by forwarding to the inner type,
+/// which allows heterogeneous errors to be handled behind a trait object:
+///
+/// ```rust
+/// use ease_off::{EaseOff, RetryableError};
+///
+/// #[derive(Debug)]
+/// struct Transient;
+///
+/// impl RetryableError for Transient {
+///     fn can_retry(&self) -> bool {
+///         true
+///     }
+/// }
+///
+/// #[derive(Debug)]
+/// struct Permanent;
+///
+/// impl RetryableError for Permanent {
+///     fn can_retry(&self) -> bool {
+///         false
+///     }
+/// }
+///
+/// let mut attempts = 0;
+/// let mut ease_off = EaseOff::start_unlimited();
+///
+/// let result = loop {
+///     let result = ease_off
+///         .try_blocking(|| -> Result<(), Box<dyn RetryableError>> {
+///             attempts += 1;
+///
+///             if attempts < 3 {
+///                 Err(Box::new(Transient))
+///             } else {
+///                 Err(Box::new(Permanent))
+///             }
+///         })
+///         .or_retry();
+///
+///     match result {
+///         Ok(Some(())) => break Ok(()),
+///         Ok(None) => continue,
+///         Err(e) => break Err(e),
+///     }
+/// };
+///
+/// assert!(result.is_err());
+/// assert_eq!(attempts, 3);
+/// ```
+pub trait RetryableError {
+    /// Returns `true` if the error is non-fatal, `false` otherwise.
+    fn can_retry(&self) -> bool;
+
+    /// Returns `true` if the error indicates that the attempt timed out.
+    ///
+    /// Used to count consecutive timeouts; see [`Options::max_consecutive_timeouts()`].
+    ///
+    /// Returns `false` by default.
+    fn is_timeout(&self) -> bool {
+        false
+    }
+
+    /// If the error indicates that the attempt timed out, returns whether retrying could help.
+    ///
+    /// A [`TimeoutClass::Permanent`] timeout is treated as fatal by `.or_retry()`
+    /// (through [`Error::can_retry()`][RetryableError::can_retry]), regardless of
+    /// [`Self::can_retry()`], so an operation that will always exceed its own timeout
+    /// is not retried pointlessly.
+    ///
+    /// Returns [`TimeoutClass::Transient`] if [`Self::is_timeout()`] returns `true`,
+    /// `None` otherwise, by default.
+    ///
+    /// ```rust
+    /// use ease_off::{EaseOff, RetryableError, TimeoutClass};
+    ///
+    /// #[derive(Debug)]
+    /// enum QueryError {
+    ///     // The network timed out; another attempt may be faster.
+    ///     ConnectTimeout,
+    ///     // The query exceeded the server's statement timeout; it will do so again.
+    ///     StatementTimeout,
+    /// }
+    ///
+    /// impl RetryableError for QueryError {
+    ///     fn can_retry(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn is_timeout(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn timeout_class(&self) -> Option<TimeoutClass> {
+    ///         Some(match self {
+    ///             Self::ConnectTimeout => TimeoutClass::Transient,
+    ///             Self::StatementTimeout => TimeoutClass::Permanent,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let mut ease_off = EaseOff::start_unlimited();
+    ///
+    /// let result = ease_off
+    ///     .try_blocking(|| Err::<(), _>(QueryError::ConnectTimeout))
+    ///     .or_retry();
+    /// assert!(matches!(result, Ok(None)));
+    ///
+    /// let result = ease_off
+    ///     .try_blocking(|| Err::<(), _>(QueryError::StatementTimeout))
+    ///     .or_retry();
+    /// assert!(matches!(result, Err(QueryError::StatementTimeout)));
+    /// ```
+    fn timeout_class(&self) -> Option<TimeoutClass> {
+        self.is_timeout().then_some(TimeoutClass::Transient)
+    }
+}
+
+/// Whether retrying after a timeout could help, returned by [`RetryableError::timeout_class()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutClass {
+    /// The timeout was caused by a transient condition, e.g. a slow network,
+    /// and the operation may succeed if retried.
+    Transient,
+    /// The operation will always exceed its timeout, e.g. because the work genuinely
+    /// takes too long; retrying cannot help.
+    Permanent,
+}
+
+impl<E: RetryableError + ?Sized> RetryableError for &E {
+    fn can_retry(&self) -> bool {
+        (**self).can_retry()
+    }
+
+    fn is_timeout(&self) -> bool {
+        (**self).is_timeout()
+    }
+
+    fn timeout_class(&self) -> Option<TimeoutClass> {
+        (**self).timeout_class()
+    }
+}
+
+impl<E: RetryableError + ?Sized> RetryableError for Box<E> {
+    fn can_retry(&self) -> bool {
+        (**self).can_retry()
+    }
+
+    fn is_timeout(&self) -> bool {
+        (**self).is_timeout()
+    }
+
+    fn timeout_class(&self) -> Option<TimeoutClass> {
+        (**self).timeout_class()
+    }
+}
+
+impl<E: RetryableError + ?Sized> RetryableError for Arc<E> {
+    fn can_retry(&self) -> bool {
+        (**self).can_retry()
+    }
+
+    fn is_timeout(&self) -> bool {
+        (**self).is_timeout()
+    }
+
+    fn timeout_class(&self) -> Option<TimeoutClass> {
+        (**self).timeout_class()
+    }
+}
+
+impl<E> AsRef<EaseOffCore> for EaseOff<E> {
+    #[inline(always)]
+    fn as_ref(&self) -> &EaseOffCore {
+        &self.core
+    }
+}
+
+impl fmt::Debug for OnMaxDelayReached {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OnMaxDelayReached(..)")
+    }
+}
+
+impl fmt::Debug for OnSoftDeadline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OnSoftDeadline(..)")
+    }
+}
+
+#[cfg(feature = "governor")]
+impl fmt::Debug for GovernorCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("GovernorCheck(..)")
+    }
+}
+
+impl<E> fmt::Debug for ErrorKey<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErrorKey(..)")
+    }
+}
+
+impl<E> fmt::Debug for FatalOn<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FatalOn(..)")
+    }
+}
+
+#[cfg(feature = "log")]
+impl log::kv::Source for LogFields {
+    fn visit<'kvs>(
+        &'kvs self,
+        visitor: &mut dyn log::kv::VisitSource<'kvs>,
+    ) -> Result<(), log::kv::Error> {
+        for (key, value) in &self.0 {
+            visitor.visit_pair(log::kv::Key::from_str(key), log::kv::Value::from(&**value))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E> fmt::Debug for RecentErrors<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecentErrors")
+            .field("limit", &self.limit)
+            .field("errors", &self.errors)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E> fmt::Debug for OnFailedAttempt<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Blocking(_) => f.write_str("Blocking(..)"),
+            #[cfg(feature = "futures")]
+            Self::Async(_) => f.write_str("Async(..)"),
+        }
+    }
+}
+
+/// The outcome of a single attempt, returned by `EaseOff::attempt()`
+/// (requires the `futures` feature).
+///
+/// An alternative to the [`ResultWrapper`] combinators for those who prefer to `match`;
+/// also convertible from the result of `.or_retry()` and friends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use = "the operation may need to be retried"]
+pub enum Outcome<T, E> {
+    /// The operation succeeded.
+    Succeeded(T),
+    /// The operation failed with a retryable error or was not ready,
+    /// and should be attempted again.
+    Retrying,
+    /// The operation failed with a fatal error, or could not be retried
+    /// (e.g. because the [deadline][EaseOff::deadline()] elapsed).
+    GaveUp(E),
+}
+
+impl<T, E> From<Result<Option<T>, E>> for Outcome<T, E> {
+    fn from(result: Result<Option<T>, E>) -> Self {
+        match result {
+            Ok(Some(success)) => Self::Succeeded(success),
+            Ok(None) => Self::Retrying,
+            Err(e) => Self::GaveUp(e),
+        }
+    }
+}
+
+/// The result of an operation which may not be ready yet, and which says how long to wait
+/// before checking again.
+///
+/// Returned by the operations passed to [`EaseOff::try_blocking_poll_after()`]
+/// and its async equivalent.
+///
+/// Not to be confused with [`std::task::Poll`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollAfter<T> {
+    /// The operation completed.
+    Ready(T),
+    /// The operation is not ready yet; check again after the given delay,
+    /// which overrides the calculated backoff.
+    Pending(Duration),
+}
+
+/// The outcome of an operation which decides for itself whether it should be retried.
+///
+/// Returned by the operations passed to [`EaseOff::try_blocking_decision()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpOutcome<T, E> {
+    /// The operation completed.
+    Done(T),
+    /// The operation should be retried after the next backoff period.
+    ///
+    /// The error, if given, is returned if the [deadline][EaseOff::deadline()] elapses.
+    Retry(Option<E>),
+    /// The operation failed and should not be retried.
+    Fatal(E),
+}
+
+/// Verdict on whether an error may be retried, cached by [`ResultWrapper::classify_once()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fatality {
+    /// The error is not fatal and the operation may be retried.
+    Retryable,
+    /// The error is fatal and the operation should not be retried.
+    Fatal,
+}
+
+/// Error type for [`EaseOff`] which includes the fatality level of the error.
+///
+/// New variants may be added in minor releases, so `match`es outside this crate
+/// need a wildcard arm. The `is_*` methods, [`Self::inner()`] and [`Self::into_inner()`]
+/// cover every variant, including future ones:
+///
+/// ```rust
+/// use ease_off::Error;
+///
+/// fn describe(e: &Error<&str>) -> String {
+///     match e {
+///         Error::MaybeRetryable(e) => format!("retrying: {e}"),
+///         Error::TimedOut(_) => format!("gave up: {}", e.inner()),
+///         _ => format!("failed: {}", e.inner()),
+///     }
+/// }
+///
+/// assert_eq!(describe(&Error::Fatal("not found")), "failed: not found");
+/// assert!(Error::Cancelled("shutting down").is_cancelled());
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error<E> {
+    /// The inner error has not been determined to be fatal yet.
+    ///
+    /// [`RetryableError::can_retry()`] passes through to the inner error,
+    /// except that a [permanent timeout][TimeoutClass::Permanent] is never retryable.
+    MaybeRetryable(E),
+    /// The error was determined to be fatal.
+    ///
+    /// Always returns `false` from [`RetryableError::can_retry()`].
+    Fatal(E),
+    /// The [deadline][EaseOff::deadline()] has elapsed.
+    ///
+    /// Contained is the error from the most recent attempt.
+    ///
+    /// Always returns `false` from [`RetryableError::can_retry()`].
+    TimedOut(TimeoutError<E>),
+    /// The retry was denied by a [`RetryLimiter`].
+    ///
+    /// Contained is the error from the most recent attempt.
+    ///
+    /// Always returns `false` from [`RetryableError::can_retry()`].
+    RateLimited(E),
+    /// Too many consecutive attempts timed out;
+    /// see [`Options::max_consecutive_timeouts()`].
+    ///
+    /// Contained is the error from the most recent attempt.
+    ///
+    /// Always returns `false` from [`RetryableError::can_retry()`].
+    TooManyTimeouts(E),
+    /// Too many distinct kinds of error were seen;
+    /// see [`Options::max_distinct_errors()`].
+    ///
+    /// Contained is the error from the most recent attempt.
+    ///
+    /// Always returns `false` from [`RetryableError::can_retry()`].
+    TooManyDistinctErrors(E),
+    /// The retry loop was told to stop by the caller, e.g. because the application
+    /// is shutting down.
+    ///
+    /// This is distinct from [`Error::Fatal`] (the operation cannot succeed) and
+    /// [`Error::TimedOut`] (the deadline elapsed). Returned by `TryAsync::with_cancellation_token()`
+    /// (requires the `tokio` feature); other cancellation mechanisms should return it
+    /// so callers can tell these cases apart.
+    ///
+    /// Contained is the error from the most recent attempt, or, if no attempt has failed,
+    /// an error produced by the cancellation mechanism (like [`EaseOff::on_zero_attempts()`]
+    /// for [`Error::TimedOut`]).
+    ///
+    /// Always returns `false` from [`RetryableError::can_retry()`].
+    Cancelled(E),
+}
+
+/// Summary of the state of an [`EaseOff`], returned by [`EaseOff::summary()`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Summary {
+    /// The number of attempts made; see [`EaseOff::num_attempts()`].
+    pub attempts: u32,
+    /// The time elapsed since [`EaseOff::started_at()`], excluding time spent
+    /// [paused][EaseOff::pause()].
+    pub elapsed: Duration,
+    /// The time remaining until the [deadline][EaseOff::deadline()], if set.
+    pub remaining: Option<Duration>,
+    /// The delay before the next attempt, before jitter is applied.
+    ///
+    /// `None` if the next attempt will be made immediately.
+    pub next_delay: Option<Duration>,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EaseOff {{ attempts: {}, elapsed: {:.1?}",
+            self.attempts, self.elapsed
+        )?;
+
+        match self.remaining {
+            Some(remaining) => write!(f, ", remaining: {remaining:.1?}")?,
+            None => f.write_str(", remaining: unlimited")?,
+        }
+
+        match self.next_delay {
+            Some(next_delay) => write!(f, ", next_delay: ~{next_delay:.1?} }}"),
+            None => f.write_str(", next_delay: none }"),
+        }
+    }
+}
+
+/// Context for the current attempt, passed to the operation by [`EaseOff::try_blocking_ctx()`]
+/// and its async equivalent.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct AttemptContext {
+    /// The number of this attempt, starting from `1`.
+    ///
+    /// Restarts from `1` after a success if [`Options::reset_on_success()`] is set,
+    /// like [`SuccessStats::attempts`].
+    pub attempt_number: u32,
+    /// The time elapsed since [`EaseOff::started_at()`], excluding time spent
+    /// [paused][EaseOff::pause()].
+    pub elapsed: Duration,
+    /// The time remaining until the [deadline][EaseOff::deadline()], if set.
+    ///
+    /// Useful to bound a per-attempt timeout so it does not outlast the deadline.
+    pub remaining: Option<Duration>,
+}
+
+/// Statistics about a successful operation, returned by [`EaseOff::succeeded()`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SuccessStats {
+    /// The number of attempts made, including the successful one.
+    pub attempts: u32,
+    /// The time elapsed since [`EaseOff::started_at()`], excluding time spent
+    /// [paused][EaseOff::pause()].
+    pub elapsed: Duration,
+    /// The total time spent waiting for backoff delays before the attempts.
+    pub total_slept: Duration,
+}
+
+impl fmt::Display for SuccessStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} attempts in {:.1?} (slept {:.1?})",
+            self.attempts, self.elapsed, self.total_slept
+        )
+    }
+}
+
+/// Summary of the backoff delays produced by an [`EaseOff`],
+/// returned by [`EaseOff::delay_summary()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DelaySummary {
+    /// The number of attempts that were delayed.
+    pub count: u32,
+    /// The sum of the delays, saturating at [`Duration::MAX`].
+    pub total: Duration,
+    /// The longest delay.
+    pub max: Duration,
+}
+
+impl DelaySummary {
+    /// Returns the mean delay, or [`Duration::ZERO`] if no attempt was delayed.
+    pub fn mean(&self) -> Duration {
+        match self.count {
+            0 => Duration::ZERO,
+            count => self.total / count,
+        }
+    }
+
+    fn record(&mut self, delay: Duration) {
+        self.count = self.count.saturating_add(1);
+        self.total = self.total.saturating_add(delay);
+        self.max = cmp::max(self.max, delay);
+    }
+}
+
+/// Error wrapper type indicating a failure due to a [deadline][EaseOff::deadline()] elapsing.
+#[derive(Debug)]
 #[non_exhaustive]
 pub struct TimeoutError<E> {
     /// The error from the most recent failed attempt.
     pub last_error: E,
+    /// The errors from the failed attempts before [`Self::last_error`], oldest first,
+    /// formatted using [`Display`][fmt::Display].
+    ///
+    /// Empty unless enabled with [`EaseOff::keep_recent_errors()`].
+    pub recent_errors: Vec<String>,
+}
+
+impl<E> TimeoutError<E> {
+    /// Map the inner error type.
+    ///
+    /// [`Self::recent_errors`] are already formatted, and so are kept as-is.
+    pub fn map<E2>(self, map: impl FnOnce(E) -> E2) -> TimeoutError<E2> {
+        TimeoutError {
+            last_error: map(self.last_error),
+            recent_errors: self.recent_errors,
+        }
+    }
+
+    /// Unwrap the inner error.
+    pub fn into_inner(self) -> E {
+        self.last_error
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for TimeoutError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deadline elapsed; last error: {}", self.last_error)?;
+
+        if let Some((first, rest)) = self.recent_errors.split_first() {
+            write!(f, "; recent errors: [{first}")?;
+
+            for error in rest {
+                write!(f, ", {error}")?;
+            }
+
+            f.write_str("]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// See the implementation for [`Error`].
+impl<E: ::core::error::Error + 'static> ::core::error::Error for TimeoutError<E> {
+    fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+        Some(&self.last_error)
+    }
 }
 
+/// Composes across nested errors: in `Error<Error<E>>`, as produced when an inner [`EaseOff`]
+/// gives up and its error becomes the error of an outer one, an outer
+/// [`Error::MaybeRetryable`] defers to the inner `Error`, so only an inner `MaybeRetryable`
+/// error can be retried. See [`Error::flatten()`] for details.
 impl<E: RetryableError> RetryableError for Error<E> {
     fn can_retry(&self) -> bool {
         match self {
-            Self::MaybeRetryable(e) => e.can_retry(),
+            Self::MaybeRetryable(e) => {
+                e.can_retry() && e.timeout_class() != Some(TimeoutClass::Permanent)
+            }
             Self::Fatal(_) => false,
             Self::TimedOut(_) => false,
+            Self::RateLimited(_) => false,
+            Self::TooManyTimeouts(_) => false,
+            Self::TooManyDistinctErrors(_) => false,
+            Self::Cancelled(_) => false,
         }
     }
+
+    fn is_timeout(&self) -> bool {
+        self.inner().is_timeout()
+    }
+
+    fn timeout_class(&self) -> Option<TimeoutClass> {
+        self.inner().timeout_class()
+    }
 }
 
 impl<E> Error<E> {
@@ -361,14 +3184,56 @@ impl<E> Error<E> {
         }
     }
 
+    /// Returns `true` if this is [`Error::MaybeRetryable`].
+    pub fn is_maybe_retryable(&self) -> bool {
+        matches!(self, Self::MaybeRetryable(_))
+    }
+
+    /// Returns `true` if this is [`Error::Fatal`].
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Self::Fatal(_))
+    }
+
+    /// Returns `true` if this is [`Error::TimedOut`], i.e. the deadline elapsed.
+    ///
+    /// Not to be confused with [`RetryableError::is_timeout()`], which reports whether
+    /// the inner error is itself a timeout.
+    pub fn is_timed_out(&self) -> bool {
+        matches!(self, Self::TimedOut(_))
+    }
+
+    /// Returns `true` if this is [`Error::RateLimited`].
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::RateLimited(_))
+    }
+
+    /// Returns `true` if this is [`Error::TooManyTimeouts`].
+    pub fn is_too_many_timeouts(&self) -> bool {
+        matches!(self, Self::TooManyTimeouts(_))
+    }
+
+    /// Returns `true` if this is [`Error::TooManyDistinctErrors`].
+    pub fn is_too_many_distinct_errors(&self) -> bool {
+        matches!(self, Self::TooManyDistinctErrors(_))
+    }
+
+    /// Returns `true` if this is [`Error::Cancelled`].
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Cancelled(_))
+    }
+
     /// Map the inner error type, retaining its retryability status.
+    ///
+    /// To collapse a nested `Error<Error<E>>`, use [`Error::flatten()`] instead.
     pub fn map<E2>(self, map: impl FnOnce(E) -> E2) -> Error<E2> {
         match self {
-            Self::TimedOut(e) => Error::TimedOut(TimeoutError {
-                last_error: map(e.last_error),
-            }),
+            Self::TimedOut(e) => Error::TimedOut(e.map(map)),
             Self::MaybeRetryable(e) => Error::MaybeRetryable(map(e)),
             Self::Fatal(e) => Error::Fatal(map(e)),
+            Self::RateLimited(e) => Error::RateLimited(map(e)),
+            Self::TooManyTimeouts(e) => Error::TooManyTimeouts(map(e)),
+            Self::TooManyDistinctErrors(e) => Error::TooManyDistinctErrors(map(e)),
+            Self::Cancelled(e) => Error::Cancelled(map(e)),
         }
     }
 
@@ -378,23 +3243,483 @@ impl<E> Error<E> {
             Self::TimedOut(e) => &e.last_error,
             Self::MaybeRetryable(e) => e,
             Self::Fatal(e) => e,
+            Self::RateLimited(e) => e,
+            Self::TooManyTimeouts(e) => e,
+            Self::TooManyDistinctErrors(e) => e,
+            Self::Cancelled(e) => e,
         }
     }
 
     /// Unwrap the inner error.
     pub fn into_inner(self) -> E {
         match self {
-            Self::TimedOut(e) => e.last_error,
+            Self::TimedOut(e) => e.into_inner(),
             Self::MaybeRetryable(e) => e,
             Self::Fatal(e) => e,
+            Self::RateLimited(e) => e,
+            Self::TooManyTimeouts(e) => e,
+            Self::TooManyDistinctErrors(e) => e,
+            Self::Cancelled(e) => e,
+        }
+    }
+}
+
+impl<E> Error<Error<E>> {
+    /// Collapse an error from nested [`EaseOff`]s into a single level.
+    ///
+    /// When the error of an inner `EaseOff` is used as the error of an outer one
+    /// (e.g. by returning it from the operation passed to the outer instance),
+    /// the result is an `Error<Error<E>>`:
+    ///
+    /// * If the outer error is [`Error::MaybeRetryable`], the outer instance has not drawn
+    ///   any conclusion, so the inner error is returned as-is: an inner [`Error::Fatal`]
+    ///   stays fatal, and an inner [`Error::TimedOut`] stays timed out.
+    /// * Otherwise, the outer variant takes precedence (e.g. the outer deadline elapsed),
+    ///   and contains the innermost error.
+    ///
+    /// The result is retryable exactly when the nested error is, as the
+    /// [`RetryableError`] implementation for `Error` already composes this way.
+    /// Note that this means an inner instance giving up is never retried by the outer
+    /// instance, as none of the variants other than `MaybeRetryable` are retryable.
+    /// To count an inner instance giving up as one failed attempt of the outer instance,
+    /// return the inner error itself (e.g. with [`Error::into_inner()`]),
+    /// as [`Options::start_within()`] demonstrates.
+    ///
+    /// ```rust
+    /// use ease_off::{Error, RetryableError};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Unavailable;
+    ///
+    /// impl RetryableError for Unavailable {
+    ///     fn can_retry(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let cases: Vec<(Error<Error<Unavailable>>, bool)> = vec![
+    ///     (Error::MaybeRetryable(Error::MaybeRetryable(Unavailable)), true),
+    ///     // An inner fatal error is not retried, even though the outer error isn't fatal.
+    ///     (Error::MaybeRetryable(Error::Fatal(Unavailable)), false),
+    ///     (Error::MaybeRetryable(Error::RateLimited(Unavailable)), false),
+    ///     (Error::Fatal(Error::MaybeRetryable(Unavailable)), false),
+    ///     (Error::Cancelled(Error::MaybeRetryable(Unavailable)), false),
+    /// ];
+    ///
+    /// for (nested, can_retry) in cases {
+    ///     assert_eq!(nested.can_retry(), can_retry);
+    ///
+    ///     let flat = nested.flatten();
+    ///     assert_eq!(flat.can_retry(), can_retry);
+    /// }
+    ///
+    /// let flat = Error::MaybeRetryable(Error::Fatal(Unavailable)).flatten();
+    /// assert!(flat.is_fatal());
+    ///
+    /// let flat = Error::Cancelled(Error::Fatal(Unavailable)).flatten();
+    /// assert!(flat.is_cancelled());
+    /// assert_eq!(flat.into_inner(), Unavailable);
+    /// ```
+    pub fn flatten(self) -> Error<E> {
+        match self {
+            Self::MaybeRetryable(inner) => inner,
+            outer => outer.map(Error::into_inner),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MaybeRetryable(e) => e.fmt(f),
+            Self::Fatal(e) => e.fmt(f),
+            Self::TimedOut(e) => e.fmt(f),
+            Self::RateLimited(e) => write!(f, "retry denied by rate limiter; last error: {e}"),
+            Self::TooManyTimeouts(e) => write!(f, "too many consecutive timeouts; last error: {e}"),
+            Self::TooManyDistinctErrors(e) => {
+                write!(f, "too many distinct errors; last error: {e}")
+            }
+            Self::Cancelled(e) => write!(f, "cancelled; last error: {e}"),
+        }
+    }
+}
+
+/// Implemented against [`core::error::Error`][::core::error::Error], which is the same trait
+/// as [`std::error::Error`] but does not require `std`. It was stabilized in Rust 1.81,
+/// which is therefore the minimum supported Rust version of this crate.
+///
+/// [`Error::MaybeRetryable`] and [`Error::Fatal`] are transparent,
+/// forwarding both `Display` and `source()` to the inner error.
+/// The other variants add context to the message and return the inner error from `source()`.
+///
+/// ```rust
+/// use std::error::Error as _;
+/// use std::io;
+/// use std::time::Duration;
+/// use ease_off::{Error, Options};
+///
+/// let fatal = Error::Fatal(io::Error::other("connection refused"));
+/// assert_eq!(fatal.to_string(), "connection refused");
+///
+/// let mut ease_off = Options::new()
+///     .start_timeout(Duration::ZERO)
+///     .on_zero_attempts(|| io::Error::other("no attempts made"));
+///
+/// let _ = ease_off
+///     .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+///     .inspect_err(|e| {
+///         assert_eq!(e.to_string(), "deadline elapsed; last error: no attempts made");
+///         assert_eq!(e.source().unwrap().to_string(), "no attempts made");
+///     })
+///     .or_retry_if(|_e| false);
+/// ```
+impl<E: ::core::error::Error + 'static> ::core::error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+        match self {
+            Self::MaybeRetryable(e) => e.source(),
+            Self::Fatal(e) => e.source(),
+            Self::TimedOut(e) => Some(&e.last_error),
+            Self::RateLimited(e) => Some(e),
+            Self::TooManyTimeouts(e) => Some(e),
+            Self::TooManyDistinctErrors(e) => Some(e),
+            Self::Cancelled(e) => Some(e),
         }
     }
 }
 
 fn blocking_sleep_until(instant: Instant) {
-    let now = Instant::now();
+    let now = clock::now();
 
     if let Some(sleep_duration) = instant.checked_duration_since(now) {
         std::thread::sleep(sleep_duration);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn on_zero_attempts_allows_zero_attempts() {
+        let mut ease_off = EaseOff::start_timeout(Duration::ZERO).on_zero_attempts(|| "skipped");
+
+        let result = ease_off
+            .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+            .or_retry_if(|e| e.is_maybe_retryable());
+
+        assert_eq!(result, Err("skipped"));
+    }
+
+    #[test]
+    fn allow_zero_attempts_skips_attempt() {
+        let mut ease_off = Options::new()
+            .allow_zero_attempts(true)
+            .start_timeout(Duration::ZERO)
+            .on_zero_attempts(|| "skipped");
+
+        let result = ease_off
+            .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+            .inspect_err(|e| assert!(matches!(e, Error::TimedOut(_))))
+            .or_retry_if(|e| e.is_maybe_retryable());
+
+        assert_eq!(result, Err("skipped"));
+    }
+
+    #[test]
+    fn allow_zero_attempts_skips_attempt_after_success() {
+        let mut ease_off = Options::new()
+            .allow_zero_attempts(true)
+            .start_timeout(Duration::from_millis(20))
+            .on_zero_attempts(|| "skipped");
+
+        let result = ease_off
+            .try_blocking(|| Ok::<_, &str>(()))
+            .or_retry_if(|e| e.is_maybe_retryable());
+        assert_eq!(result, Ok(Some(())));
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        let result = ease_off
+            .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+            .or_retry_if(|e| e.is_maybe_retryable());
+        assert_eq!(result, Err("skipped"));
+    }
+
+    #[test]
+    #[should_panic = "no error to return"]
+    fn allow_zero_attempts_without_error_panics() {
+        let mut ease_off = Options::new()
+            .allow_zero_attempts(true)
+            .start_timeout(Duration::ZERO);
+
+        let _ = ease_off
+            .try_blocking(|| -> Result<(), &str> { unreachable!("should not be called") })
+            .or_retry_if(|e| e.is_maybe_retryable());
+    }
+
+    #[test]
+    fn map_err_forgets_distinct_errors() {
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::ZERO)
+            .max_distinct_errors(2)
+            .start_unlimited()
+            .distinct_errors_by(|e: &&str| *e);
+
+        let result = ease_off
+            .try_blocking(|| Err::<(), _>("a"))
+            .or_retry_if(|_e| true);
+        assert_eq!(result, Ok(None));
+
+        // The key of "a" must not be counted against the mapped errors.
+        let mut ease_off = ease_off
+            .map_err(String::from)
+            .distinct_errors_by(|e: &String| e.clone());
+
+        let result = ease_off
+            .try_blocking(|| Err::<(), _>("b".to_string()))
+            .or_retry_if(|_e| true);
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn resume_does_not_extend_deadline_by_sleep_again() {
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::from_millis(50))
+            .multiplier(1.0)
+            .jitter(0.0)
+            .deadline_excludes_sleep(true)
+            .start_timeout(Duration::from_secs(60));
+
+        // The first retry is immediate, then the second sleeps for 50 milliseconds.
+        for _ in 0..3 {
+            let result = ease_off
+                .try_blocking(|| Err::<(), _>("oops"))
+                .or_retry_if(|e| e.is_maybe_retryable());
+            assert_eq!(result, Ok(None));
+        }
+
+        assert!(ease_off.delay_summary().total >= Duration::from_millis(50));
+
+        let deadline = ease_off.deadline().unwrap();
+        let paused_at = clock::now();
+
+        for _ in 0..3 {
+            ease_off.pause();
+            ease_off.resume();
+        }
+
+        // Only by the time spent paused.
+        let extended = ease_off.deadline().unwrap() - deadline;
+        assert!(
+            extended <= clock::now() - paused_at,
+            "extended by {extended:?}"
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum JobError {
+        Failed,
+        NotReady,
+    }
+
+    impl RetryableError for JobError {
+        fn can_retry(&self) -> bool {
+            true
+        }
+    }
+
+    /// The deadline elapses before the second retry, with no attempt having failed.
+    fn ease_off_past_deadline_after_provisional() -> EaseOff<JobError> {
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::from_secs(60))
+            .jitter(0.0)
+            .start_timeout(Duration::from_secs(1));
+
+        // The first retry is immediate.
+        for progress in [20, 60] {
+            let result = ease_off
+                .try_blocking(|| Ok(progress))
+                .keep_and_retry_if(|&progress| progress < 100, || JobError::NotReady);
+            assert_eq!(result, Ok(Some(progress)));
+        }
+
+        ease_off
+    }
+
+    #[test]
+    fn keep_and_retry_if_times_out_with_make_error() {
+        let mut ease_off = ease_off_past_deadline_after_provisional();
+
+        let mut timed_out = false;
+
+        let result = ease_off
+            .try_blocking(|| -> Result<u32, _> { unreachable!("should not be called") })
+            .inspect_err(|e| timed_out = e.is_timed_out())
+            .keep_and_retry_if(|&progress| progress < 100, || JobError::NotReady);
+
+        assert_eq!(result, Err(JobError::NotReady));
+        assert!(timed_out);
+    }
+
+    #[test]
+    fn peek_times_out_with_not_ready_error() {
+        let ease_off = ease_off_past_deadline_after_provisional();
+
+        match ease_off.next_retry_at_peek() {
+            Err(Error::TimedOut(e)) => assert_eq!(e.last_error, JobError::NotReady),
+            other => panic!("expected `TimedOut`, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn or_retry_after_not_ready_times_out() {
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::from_secs(60))
+            .jitter(0.0)
+            .start_timeout(Duration::from_secs(1));
+
+        // The first retry is immediate.
+        for _ in 0..2 {
+            let result = ease_off
+                .try_blocking_poll(|| Ok::<Option<()>, _>(None), || JobError::NotReady)
+                .or_retry();
+            assert_eq!(result, Ok(None));
+        }
+
+        let result = ease_off
+            .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+            .or_retry();
+        assert_eq!(result, Err(JobError::NotReady));
+    }
+
+    #[test]
+    fn failed_attempt_takes_precedence_over_not_ready() {
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::from_secs(60))
+            .jitter(0.0)
+            .start_timeout(Duration::from_secs(1));
+
+        let result = ease_off
+            .try_blocking_poll(|| Ok::<Option<()>, _>(None), || JobError::NotReady)
+            .or_retry();
+        assert_eq!(result, Ok(None));
+
+        let result = ease_off
+            .try_blocking_poll(
+                || Err::<Option<()>, _>(JobError::Failed),
+                || JobError::NotReady,
+            )
+            .or_retry();
+        assert_eq!(result, Ok(None));
+
+        let result = ease_off
+            .try_blocking_poll(
+                || -> Result<Option<()>, _> { unreachable!() },
+                || JobError::NotReady,
+            )
+            .or_retry();
+        assert_eq!(result, Err(JobError::Failed));
+    }
+
+    #[test]
+    fn limiter_denies_retry_after_not_ready() {
+        let limiter = Arc::new(RetryLimiter::new(1, Duration::from_secs(3600)));
+        assert!(limiter.try_acquire());
+
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::from_millis(1))
+            .start_unlimited()
+            .with_limiter(limiter);
+
+        let result = ease_off
+            .try_blocking(|| Ok(50))
+            .keep_and_retry_if(|&progress| progress < 100, || JobError::NotReady);
+        assert_eq!(result, Ok(Some(50)));
+
+        let mut rate_limited = false;
+
+        let result = ease_off
+            .try_blocking(|| -> Result<u32, _> { unreachable!("should not be called") })
+            .inspect_err(|e| rate_limited = e.is_rate_limited())
+            .keep_and_retry_if(|&progress| progress < 100, || JobError::NotReady);
+
+        assert_eq!(result, Err(JobError::NotReady));
+        assert!(rate_limited);
+    }
+
+    #[test]
+    fn attempt_matches_attempt_number() {
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::from_millis(1))
+            .start_unlimited();
+
+        for expected in 1..=3 {
+            let mut attempt_number = 0;
+
+            let wrapper = ease_off.try_blocking_ctx(|ctx| {
+                attempt_number = ctx.attempt_number;
+                Err::<(), _>("oops")
+            });
+
+            assert_eq!(wrapper.attempt(), expected);
+            assert_eq!(attempt_number, expected);
+
+            let result = wrapper.or_retry_if(|e| e.is_maybe_retryable());
+            assert_eq!(result, Ok(None));
+        }
+    }
+
+    #[cfg(feature = "governor")]
+    fn governor(period: Duration) -> Arc<governor::DefaultDirectRateLimiter> {
+        let quota = governor::Quota::with_period(period).expect("period should be non-zero");
+        Arc::new(governor::RateLimiter::direct(quota))
+    }
+
+    #[test]
+    #[cfg(feature = "governor")]
+    fn governor_rechecks_after_sleep() {
+        let limiter = governor(Duration::from_millis(50));
+        let started_at = Instant::now();
+
+        // The burst of one is taken by the first attempt; the other two are denied
+        // and wake at the same time, but only one can take the next cell.
+        std::thread::scope(|scope| {
+            for _ in 0..3 {
+                let limiter = limiter.clone();
+
+                scope.spawn(move || {
+                    let mut ease_off = EaseOff::start_unlimited().with_governor(limiter);
+
+                    let result = ease_off
+                        .try_blocking(|| Ok::<_, &str>(()))
+                        .or_retry_if(|e| e.is_maybe_retryable());
+                    assert_eq!(result, Ok(Some(())));
+                });
+            }
+        });
+
+        assert!(started_at.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    #[cfg(feature = "governor")]
+    fn governor_times_out_retry_past_deadline() {
+        let mut ease_off = EaseOff::start_timeout(Duration::from_secs(1))
+            .with_governor(governor(Duration::from_secs(60)));
+
+        let result = ease_off
+            .try_blocking(|| Err::<(), _>("oops"))
+            .or_retry_if(|e| e.is_maybe_retryable());
+        assert_eq!(result, Ok(None));
+
+        let mut timed_out = false;
+
+        let result = ease_off
+            .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+            .inspect_err(|e| timed_out = e.is_timed_out())
+            .or_retry_if(|e| e.is_maybe_retryable());
+
+        assert_eq!(result, Err("oops"));
+        assert!(timed_out);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::clock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Caps the rate of retries across any number of [`EaseOff`][crate::EaseOff] instances.
+///
+/// This protects a shared backend from a surge of retries from many controllers at once,
+/// e.g. when a dependency goes down and every in-flight operation starts backing off.
+///
+/// Implemented as a leaky bucket allowing up to `max_retries` retries in a burst,
+/// refilling at a constant rate of `max_retries` per `period`.
+/// Only retries are counted; the first attempt of an operation is never limited.
+///
+/// Share an instance between controllers by wrapping it in an [`Arc`][std::sync::Arc]
+/// and passing it to [`EaseOff::with_limiter()`][crate::EaseOff::with_limiter].
+///
+/// ### Denial
+/// If a controller attempts to retry when the limit has been reached,
+/// the attempt is not made and [`Error::RateLimited`][crate::Error::RateLimited] is returned
+/// containing the error from the previous attempt.
+/// The operation is not retried, regardless of the current backoff or the deadline.
+///
+/// ### Thread-Safety
+/// The state of the limiter is a single atomic integer updated in a compare-and-swap loop,
+/// so it is lock-free and may be shared between any number of threads.
+///
+/// ```rust
+/// use std::sync::{Arc, LazyLock};
+/// use std::time::Duration;
+/// use ease_off::{EaseOff, RetryLimiter};
+///
+/// // At most 100 retries per second for the whole process.
+/// static LIMITER: LazyLock<Arc<RetryLimiter>> =
+///     LazyLock::new(|| Arc::new(RetryLimiter::new(100, Duration::from_secs(1))));
+///
+/// let mut ease_off = EaseOff::<std::io::Error>::start_timeout(Duration::from_secs(30))
+///     .with_limiter(LIMITER.clone());
+/// ```
+#[derive(Debug)]
+pub struct RetryLimiter {
+    base: Instant,
+    interval_nanos: u64,
+    tolerance_nanos: u64,
+    // The "theoretical arrival time" of the next retry, in nanoseconds since `base`.
+    next_at_nanos: AtomicU64,
+}
+
+impl RetryLimiter {
+    /// Allow up to `max_retries` retries per `period`.
+    ///
+    /// ### Panics
+    /// If `max_retries` is zero, or if `period / max_retries` is less than one nanosecond,
+    /// which would not limit retries at all.
+    pub fn new(max_retries: u32, period: Duration) -> Self {
+        assert_ne!(max_retries, 0, "`max_retries` must not be zero");
+
+        let tolerance_nanos = duration_as_nanos_saturating(period);
+        let interval_nanos = tolerance_nanos / u64::from(max_retries);
+
+        assert_ne!(
+            interval_nanos, 0,
+            "`period / max_retries` must be at least one nanosecond, got {period:?} / {max_retries}"
+        );
+
+        Self {
+            base: clock::now(),
+            interval_nanos,
+            tolerance_nanos,
+            next_at_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Attempt to acquire permission for one retry.
+    ///
+    /// Returns `true` if the retry is allowed, `false` if the limit has been reached.
+    pub fn try_acquire(&self) -> bool {
+        let now = duration_as_nanos_saturating(clock::now().saturating_duration_since(self.base));
+
+        self.next_at_nanos
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |next_at| {
+                let next_at = next_at.max(now).saturating_add(self.interval_nanos);
+
+                (next_at - now <= self.tolerance_nanos).then_some(next_at)
+            })
+            .is_ok()
+    }
+}
+
+fn duration_as_nanos_saturating(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_burst_then_denies() {
+        let limiter = RetryLimiter::new(3, Duration::from_secs(3600));
+
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    #[should_panic = "must not be zero"]
+    fn zero_max_retries() {
+        RetryLimiter::new(0, Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic = "must be at least one nanosecond"]
+    fn zero_period() {
+        RetryLimiter::new(1, Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic = "must be at least one nanosecond"]
+    fn zero_interval() {
+        RetryLimiter::new(100, Duration::from_nanos(99));
+    }
+}
@@ -0,0 +1,129 @@
+/// Retry an operation until it succeeds, returns a fatal error or the deadline elapses.
+///
+/// Expands to the standard loop, evaluating to `Result<T, E>`
+/// (`Ok` on success, `Err` if the error is fatal or the deadline elapsed):
+///
+/// ```rust,ignore
+/// loop {
+///     match ease_off.try_blocking(|| op).or_retry() {
+///         Ok(Some(value)) => break Ok(value),
+///         Ok(None) => continue,
+///         Err(e) => break Err(e),
+///     }
+/// }
+/// ```
+///
+/// The operation is given as an expression which is evaluated once per attempt.
+/// The error type must implement [`RetryableError`][crate::RetryableError];
+/// if you need [`ResultWrapper::inspect_err()`][crate::ResultWrapper::inspect_err]
+/// or another way of checking errors, write the loop yourself.
+///
+/// Prefix the operation with `async` to make the attempts with
+/// [`EaseOff::try_async_with()`][crate::EaseOff::try_async_with] instead of
+/// [`EaseOff::try_blocking()`][crate::EaseOff::try_blocking]; the expression must then
+/// evaluate to a `Future` (e.g. a call to an `async fn`), and the macro must be used
+/// in an async context.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use ease_off::{EaseOff, RetryableError};
+///
+/// #[derive(Debug)]
+/// struct Flaky;
+///
+/// impl RetryableError for Flaky {
+///     fn can_retry(&self) -> bool {
+///         true
+///     }
+/// }
+///
+/// let mut attempts = 0;
+/// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+///
+/// let value = ease_off::retry!(ease_off, {
+///     attempts += 1;
+///
+///     if attempts < 3 {
+///         Err(Flaky)
+///     } else {
+///         Ok("done")
+///     }
+/// })?;
+///
+/// assert_eq!(value, "done");
+/// assert_eq!(attempts, 3);
+/// # Ok::<_, Flaky>(())
+/// ```
+///
+/// Async:
+///
+#[cfg_attr(feature = "tokio", doc = "```rust")]
+#[cfg_attr(not(feature = "tokio"), doc = "```rust,ignore")]
+/// use std::time::Duration;
+/// use ease_off::{EaseOff, RetryableError};
+///
+/// #[derive(Debug)]
+/// struct Flaky;
+///
+/// impl RetryableError for Flaky {
+///     fn can_retry(&self) -> bool {
+///         true
+///     }
+/// }
+///
+/// struct Client {
+///     attempts: u32,
+/// }
+///
+/// impl Client {
+///     async fn fetch(&mut self) -> Result<u32, Flaky> {
+///         self.attempts += 1;
+///
+///         if self.attempts < 3 {
+///             Err(Flaky)
+///         } else {
+///             Ok(self.attempts)
+///         }
+///     }
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), Flaky> {
+/// let mut client = Client { attempts: 0 };
+/// let mut ease_off = EaseOff::start_timeout(Duration::from_secs(10));
+///
+/// let value = ease_off::retry!(ease_off, async client.fetch())?;
+///
+/// assert_eq!(value, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! retry {
+    ($ease_off:expr, async $op:expr $(,)?) => {{
+        let ease_off = &mut $ease_off;
+
+        loop {
+            match ease_off.try_async_with(|| $op).await.or_retry() {
+                ::std::result::Result::Ok(::std::option::Option::Some(value)) => {
+                    break ::std::result::Result::Ok(value)
+                }
+                ::std::result::Result::Ok(::std::option::Option::None) => continue,
+                ::std::result::Result::Err(e) => break ::std::result::Result::Err(e),
+            }
+        }
+    }};
+    ($ease_off:expr, $op:expr $(,)?) => {{
+        let ease_off = &mut $ease_off;
+
+        loop {
+            match ease_off.try_blocking(|| $op).or_retry() {
+                ::std::result::Result::Ok(::std::option::Option::Some(value)) => {
+                    break ::std::result::Result::Ok(value)
+                }
+                ::std::result::Result::Ok(::std::option::Option::None) => continue,
+                ::std::result::Result::Err(e) => break ::std::result::Result::Err(e),
+            }
+        }
+    }};
+}
@@ -1,6 +1,7 @@
+use crate::clock;
 use crate::core::EaseOffCore;
 use crate::EaseOff;
-use std::num::Saturating;
+use std::cmp;
 use std::time::{Duration, Instant};
 
 /// Configuration options for [`EaseOff`] and [`EaseOffCore`].
@@ -20,8 +21,25 @@ pub struct Options {
     pub(crate) multiplier: f32,
     pub(crate) jitter: f32,
     pub(crate) initial_jitter: f32,
+    pub(crate) initial_immediate_probability: Option<f32>,
     pub(crate) initial_delay: Duration,
     pub(crate) max_delay: Duration,
+    pub(crate) fast_first_retry: Option<Duration>,
+    pub(crate) allow_zero_attempts: bool,
+    pub(crate) reset_on_success: bool,
+    pub(crate) deadline_jitter: f32,
+    pub(crate) deadline_excludes_sleep: bool,
+    pub(crate) base_spread: f32,
+    pub(crate) soft_deadline: Option<Duration>,
+    pub(crate) quantize_delay: Option<Duration>,
+    pub(crate) max_consecutive_timeouts: Option<u32>,
+    pub(crate) max_distinct_errors: Option<u32>,
+    pub(crate) history_capacity: Option<usize>,
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    pub(crate) warn_on_thundering_herd: bool,
+    pub(crate) delay_overrides: [Option<(u32, Duration)>; Options::MAX_DELAY_OVERRIDES],
+    pub(crate) fixed_schedule: Option<&'static [Duration]>,
+    pub(crate) warmup: Option<(u32, &'static Options)>,
 }
 
 impl Options {
@@ -32,10 +50,29 @@ impl Options {
         multiplier: 2.0,
         jitter: 0.25,
         initial_jitter: 0.0,
+        initial_immediate_probability: None,
         initial_delay: Duration::from_millis(150),
         max_delay: Duration::from_secs(60), // one minute
+        fast_first_retry: None,
+        allow_zero_attempts: false,
+        reset_on_success: true,
+        deadline_jitter: 0.0,
+        deadline_excludes_sleep: false,
+        base_spread: 0.0,
+        soft_deadline: None,
+        quantize_delay: None,
+        max_consecutive_timeouts: None,
+        max_distinct_errors: None,
+        history_capacity: None,
+        warn_on_thundering_herd: false,
+        delay_overrides: [None; Options::MAX_DELAY_OVERRIDES],
+        fixed_schedule: None,
+        warmup: None,
     };
 
+    /// The maximum number of overrides that may be set with [`Self::delay_override()`].
+    pub const MAX_DELAY_OVERRIDES: usize = 8;
+
     /// Returns [`Self::DEFAULT`].
     #[inline(always)]
     pub const fn new() -> Self {
@@ -50,6 +87,27 @@ impl Options {
     ///
     /// Any multiplication that results in an invalid value for [`Duration`] saturates
     /// to [`Duration::MAX`] or [`max_delay`][Self::max_delay], whichever is lower.
+    ///
+    /// The exponent applied to `multiplier` also saturates, at [`i32::MAX`],
+    /// for attempt numbers beyond that; the delay simply stays at `max_delay`.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new()
+    ///     .jitter(0.0)
+    ///     .max_delay(Duration::from_secs(60))
+    ///     .into_core();
+    ///
+    /// let retry = core
+    ///     .nth_retry_at_detailed(u32::MAX, Instant::now(), None, &mut rand::thread_rng())
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(retry.delay, Duration::from_secs(60));
+    /// assert!(retry.clamped_to_max);
+    /// ```
     #[inline(always)]
     pub const fn multiplier(self, multiplier: f32) -> Self {
         Self { multiplier, ..self }
@@ -75,6 +133,33 @@ impl Options {
     /// If `jitter >= 1`, the next delay can be anywhere between `[0, next_delay]`,
     /// which means the next attempt _could_ happen immediately, without waiting.
     ///
+    /// | `jitter`              | Resulting delay                         |
+    /// |-----------------------|-----------------------------------------|
+    /// | `NaN`, `<= 0`         | exactly `next_delay`                    |
+    /// | `0 < jitter < 1`      | `(next_delay * (1 - jitter), next_delay]` |
+    /// | `>= 1` (incl. `+inf`) | `(0, next_delay]`                       |
+    ///
+    /// The same rules apply to [`initial_jitter`][Self::initial_jitter]
+    /// and [`deadline_jitter`][Self::deadline_jitter].
+    /// Jitter is only ever subtracted, so the delay is never negative
+    /// and never longer than without jitter:
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new()
+    ///     .initial_delay(Duration::from_secs(10))
+    ///     .jitter(0.5)
+    ///     .into_core();
+    ///
+    /// let now = Instant::now();
+    /// let retry_at = core.nth_retry_at(1, now, None, &mut rand::thread_rng()).unwrap().unwrap();
+    ///
+    /// assert!(retry_at >= now + Duration::from_secs(5));
+    /// assert!(retry_at <= now + Duration::from_secs(10));
+    /// ```
+    ///
     /// [thundering herd]: https://en.wikipedia.org/wiki/Thundering_herd_problem
     #[inline(always)]
     pub const fn jitter(self, jitter: f32) -> Self {
@@ -121,7 +206,127 @@ impl Options {
         self.initial_jitter
     }
 
+    /// Make the first attempt immediately with probability `probability`,
+    /// and otherwise only after [`initial_delay`][Self::initial_delay].
+    ///
+    /// Where [`initial_jitter`][Self::initial_jitter] spreads the first attempts of many
+    /// instances started at the same time over an interval, this splits them into two groups:
+    /// on average, a fraction `probability` of the instances attempt immediately
+    /// (e.g. a canary probing the resource), while the rest hang back.
+    ///
+    /// The delay before attempt `0` is therefore distributed as follows:
+    ///
+    /// * With probability `probability`, zero.
+    /// * Otherwise, as calculated without this option, except that it is never skipped:
+    ///   `initial_delay`, jittered by `initial_jitter` (if set) to a random delay in
+    ///   `(initial_delay * (1 - initial_jitter), initial_delay]`,
+    ///   or the [override][Self::delay_override] for attempt `0`, if set.
+    ///
+    /// As with `initial_jitter`, this also applies to the first retry, which is scheduled
+    /// as attempt `0` as well.
+    ///
+    /// The choice is made using the random number generator each time attempt `0` is scheduled.
+    /// `probability` is effectively clamped to the interval `[0, 1]`; `NaN` is treated as `0`,
+    /// so every instance waits.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new()
+    ///     .initial_delay(Duration::from_secs(1))
+    ///     .initial_immediate_probability(0.1)
+    ///     .into_core();
+    ///
+    /// let now = Instant::now();
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let immediate = (0..1000)
+    ///     .filter(|_| {
+    ///         match core.nth_retry_at(0, now, None, &mut rng).unwrap() {
+    ///             None => true,
+    ///             Some(retry_at) => {
+    ///                 // No `initial_jitter`, so the rest wait exactly `initial_delay`.
+    ///                 assert_eq!(retry_at, now + Duration::from_secs(1));
+    ///                 false
+    ///             }
+    ///         }
+    ///     })
+    ///     .count();
+    ///
+    /// // About 100 of 1000 go immediately.
+    /// assert!(immediate > 20 && immediate < 250, "{immediate}");
+    /// ```
+    #[inline(always)]
+    pub const fn initial_immediate_probability(self, probability: f32) -> Self {
+        Self {
+            initial_immediate_probability: Some(probability),
+            ..self
+        }
+    }
+
+    /// Get the probability of making the first attempt immediately, if set.
+    ///
+    /// See [`Self::initial_immediate_probability()`] for details.
+    #[inline(always)]
+    pub const fn get_initial_immediate_probability(&self) -> Option<f32> {
+        self.initial_immediate_probability
+    }
+
+    /// Disable all randomness, so every instance follows exactly the same schedule.
+    ///
+    /// Sets [`jitter`][Self::jitter], [`initial_jitter`][Self::initial_jitter],
+    /// [`deadline_jitter`][Self::deadline_jitter] and [`base_spread`][Self::base_spread]
+    /// to zero, and unsets
+    /// [`initial_immediate_probability`][Self::initial_immediate_probability].
+    /// With no jitter to apply,
+    /// a random number generator is never acquired.
+    ///
+    /// **This intentionally creates the [thundering herd problem]**: clients that fail at the
+    /// same time will all retry at the same time. This is useful for load testing with
+    /// a controlled, synchronized load pattern, but should not be used in production.
+    ///
+    /// Does not affect the `steady` options passed to [`Self::with_warmup()`];
+    /// call this on those as well if applicable.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// const OPTIONS: Options = Options::new()
+    ///     .initial_jitter(0.5)
+    ///     .deadline_jitter(0.5)
+    ///     .deterministic();
+    ///
+    /// let now = Instant::now();
+    /// let (a, b) = (OPTIONS.into_core(), OPTIONS.into_core());
+    ///
+    /// for n in 0..20 {
+    ///     let a = a.nth_retry_at(n, now, None, &mut rand::thread_rng()).unwrap();
+    ///     let b = b.nth_retry_at(n, now, None, &mut rand::thread_rng()).unwrap();
+    ///     assert_eq!(a, b);
+    /// }
+    ///
+    /// let deadline = now + Duration::from_secs(60);
+    /// assert_eq!(OPTIONS.start_deadline::<()>(deadline).deadline(), Some(deadline));
+    /// ```
+    ///
+    /// [thundering herd problem]: https://en.wikipedia.org/wiki/Thundering_herd_problem
+    #[inline(always)]
+    pub const fn deterministic(self) -> Self {
+        Self {
+            jitter: 0.0,
+            initial_jitter: 0.0,
+            initial_immediate_probability: None,
+            deadline_jitter: 0.0,
+            base_spread: 0.0,
+            ..self
+        }
+    }
+
     /// Set the delay for the first backoff attempt.
+    ///
+    /// Capped at [`max_delay`][Self::max_delay].
     #[inline(always)]
     pub const fn initial_delay(self, initial_delay: Duration) -> Self {
         Self {
@@ -139,6 +344,26 @@ impl Options {
     }
 
     /// Set the maximum delay to wait between backoff attempts.
+    ///
+    /// This applies to every attempt, including the initial delay before the first attempt
+    /// (if [`initial_jitter`][Self::initial_jitter] is set)
+    /// and the [`initial_delay`][Self::initial_delay] if it is larger than `max_delay`:
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new()
+    ///     .initial_delay(Duration::from_secs(10))
+    ///     .max_delay(Duration::from_secs(5))
+    ///     .jitter(0.0)
+    ///     .into_core();
+    ///
+    /// let now = Instant::now();
+    /// let retry_at = core.nth_retry_at(1, now, None, &mut rand::thread_rng()).unwrap();
+    ///
+    /// assert_eq!(retry_at, Some(now + Duration::from_secs(5)));
+    /// ```
     #[inline(always)]
     pub const fn max_delay(self, max_delay: Duration) -> Self {
         Self { max_delay, ..self }
@@ -152,6 +377,877 @@ impl Options {
         self.max_delay
     }
 
+    /// Set a fixed delay to use for attempt `1`, i.e. the first retry that is delayed at all.
+    ///
+    /// Transient failures often clear almost instantly, so it can be worthwhile to retry
+    /// quickly before beginning to back off in earnest.
+    ///
+    /// This overrides only the delay for attempt `1`, which is otherwise
+    /// [`initial_delay`][Self::initial_delay]; [`initial_jitter`][Self::initial_jitter]
+    /// still governs attempt `0`, and the delays from attempt `2` onward are calculated as normal.
+    ///
+    /// [`EaseOff`] retries the first failure immediately (attempt `0`, unless `initial_jitter`
+    /// is set), so this is the delay before the _second_ retry.
+    /// With the default options and `fast_first_retry(Duration::from_millis(10))`,
+    /// the schedule (before jitter) looks like:
+    ///
+    /// | Attempt | `EaseOff` retry | Delay                      |
+    /// |---------|-----------------|----------------------------|
+    /// | 0       | 1st             | none                       |
+    /// | 1       | 2nd             | 10ms (`fast_first_retry`)  |
+    /// | 2       | 3rd             | 300ms                      |
+    /// | 3       | 4th             | 600ms                      |
+    /// | ...     | ...             | ... up to `max_delay`      |
+    ///
+    /// [Jitter][Self::jitter] is applied to this delay like any other retry,
+    /// and it is capped at [`max_delay`][Self::max_delay].
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(4))
+    ///     .fast_first_retry(Duration::from_millis(1))
+    ///     .jitter(0.0)
+    ///     .start_unlimited()
+    ///     .with_history(10);
+    ///
+    /// // The first attempt, then three retries.
+    /// for _ in 0..4 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// // The first retry was immediate.
+    /// assert_eq!(*ease_off.realized_delays(), [1, 8].map(Duration::from_millis));
+    /// ```
+    #[inline(always)]
+    pub const fn fast_first_retry(self, fast_first_retry: Duration) -> Self {
+        Self {
+            fast_first_retry: Some(fast_first_retry),
+            ..self
+        }
+    }
+
+    /// Get the fixed delay to use for attempt `1`, if set.
+    ///
+    /// See [`Self::fast_first_retry()`] for details.
+    #[inline(always)]
+    pub const fn get_fast_first_retry(&self) -> Option<Duration> {
+        self.fast_first_retry
+    }
+
+    /// Override the delay before the `n`th attempt.
+    ///
+    /// Useful for tuning against known backend behavior, e.g. waiting at least 10 seconds
+    /// before attempt `3` because that is when a cache is known to be warm.
+    /// The delays for other attempts are calculated as normal.
+    ///
+    /// An override takes precedence over the calculated delay (including
+    /// [`initial_delay`][Self::initial_delay] for attempt `0` and
+    /// [`fast_first_retry`][Self::fast_first_retry] for attempt `1`)
+    /// and is not subject to [jitter][Self::jitter], but is still capped at
+    /// [`max_delay`][Self::max_delay] and will not be scheduled after the deadline.
+    ///
+    /// Setting an override for the same `n` again replaces the previous value.
+    ///
+    /// ### Panics
+    /// If more than [`Self::MAX_DELAY_OVERRIDES`] distinct attempts are overridden.
+    /// If this happens in a `const` context, it is a compile error instead.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// const BACKOFF_OPTS: ease_off::Options = ease_off::Options::new()
+    ///     .delay_override(3, Duration::from_secs(10));
+    ///
+    /// assert_eq!(BACKOFF_OPTS.get_delay_override(3), Some(Duration::from_secs(10)));
+    /// assert_eq!(BACKOFF_OPTS.get_delay_override(4), None);
+    /// ```
+    pub const fn delay_override(mut self, n: u32, delay: Duration) -> Self {
+        let mut i = 0;
+
+        while i < Self::MAX_DELAY_OVERRIDES {
+            match self.delay_overrides[i] {
+                Some((existing, _)) if existing != n => i += 1,
+                _ => {
+                    self.delay_overrides[i] = Some((n, delay));
+                    return self;
+                }
+            }
+        }
+
+        panic!("exceeded `Options::MAX_DELAY_OVERRIDES`")
+    }
+
+    /// Get the overridden delay before the `n`th attempt, if set.
+    ///
+    /// See [`Self::delay_override()`] for details.
+    pub const fn get_delay_override(&self, n: u32) -> Option<Duration> {
+        let mut i = 0;
+
+        while i < Self::MAX_DELAY_OVERRIDES {
+            match self.delay_overrides[i] {
+                Some((existing, delay)) if existing == n => return Some(delay),
+                Some(_) => i += 1,
+                None => return None,
+            }
+        }
+
+        None
+    }
+
+    /// Set whether to skip the first attempt if the deadline has already elapsed.
+    ///
+    /// By default, [`EaseOff`] always makes one attempt, even if the timeout is zero
+    /// or the deadline has elapsed by the time the first attempt is made.
+    ///
+    /// If `true`, the first `try_*` call after the deadline has elapsed does not run
+    /// the operation and returns [`Error::TimedOut`][crate::Error::TimedOut] instead.
+    /// The same applies to the first attempt after a success.
+    ///
+    /// Because no attempt was made, there is no previous error to return,
+    /// so the error is produced by the function passed to [`EaseOff::on_zero_attempts()`],
+    /// which also turns this on for that instance.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{Error, Options};
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .allow_zero_attempts(true)
+    ///     .start_timeout(Duration::ZERO)
+    ///     .on_zero_attempts(|| "deadline elapsed before the first attempt");
+    ///
+    /// let result = ease_off
+    ///     .try_blocking(|| -> Result<(), _> { unreachable!("should not be called") })
+    ///     .inspect_err(|e| assert!(matches!(e, Error::TimedOut(_))))
+    ///     .or_retry_if(|e| e.is_maybe_retryable());
+    ///
+    /// assert_eq!(result, Err("deadline elapsed before the first attempt"));
+    /// ```
+    ///
+    /// ### Panics
+    /// The skipped attempt panics if [`EaseOff::on_zero_attempts()`] was not called,
+    /// as there is then no error to return.
+    #[inline(always)]
+    pub const fn allow_zero_attempts(self, allow_zero_attempts: bool) -> Self {
+        Self {
+            allow_zero_attempts,
+            ..self
+        }
+    }
+
+    /// Get whether the first attempt is skipped if the deadline has already elapsed.
+    ///
+    /// See [`Self::allow_zero_attempts()`] for details.
+    #[inline(always)]
+    pub const fn get_allow_zero_attempts(&self) -> bool {
+        self.allow_zero_attempts
+    }
+
+    /// Set whether the schedule starts over after a successful attempt (default `true`).
+    ///
+    /// After a success, the next attempt is always made immediately, as there is no failure
+    /// to back off from. If `true`, [`EaseOff::num_attempts()`] is also reset to zero,
+    /// so if that attempt fails, the backoff starts again from the initial delay.
+    ///
+    /// If `false`, the count is kept, so the schedule continues from where it left off.
+    /// This is useful for a flapping dependency, where an occasional success
+    /// should not reset the backoff to its shortest delay.
+    /// The statistics returned by [`EaseOff::succeeded()`] then also accumulate
+    /// over all attempts rather than since the previous success.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// for (reset_on_success, expected) in [(true, 1), (false, 3)] {
+    ///     let mut ease_off = Options::new()
+    ///         .initial_delay(Duration::from_millis(1))
+    ///         .reset_on_success(reset_on_success)
+    ///         .start_unlimited();
+    ///
+    ///     let mut results = vec![Err("flap"), Ok(()), Err("flap"), Err("flap")];
+    ///
+    ///     // Fail twice, then succeed.
+    ///     for _ in 0..3 {
+    ///         let _ = ease_off
+    ///             .try_blocking(|| results.pop().unwrap())
+    ///             .or_retry_if(|_e| true);
+    ///     }
+    ///
+    ///     // The next failure either starts over, or continues the schedule.
+    ///     let attempt = ease_off
+    ///         .try_blocking(|| results.pop().unwrap())
+    ///         .attempt();
+    ///
+    ///     assert_eq!(attempt, expected);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub const fn reset_on_success(self, reset_on_success: bool) -> Self {
+        Self {
+            reset_on_success,
+            ..self
+        }
+    }
+
+    /// Get whether the schedule starts over after a successful attempt.
+    ///
+    /// See [`Self::reset_on_success()`] for details.
+    #[inline(always)]
+    pub const fn get_reset_on_success(&self) -> bool {
+        self.reset_on_success
+    }
+
+    /// Set the maximum jitter factor to apply to the deadline.
+    ///
+    /// When many instances share the same deadline, they all give up at the same moment,
+    /// which can cause a stampede of whatever happens next (e.g. failing over to a backup).
+    ///
+    /// If set, the deadline of each [`EaseOff`] is moved **earlier** once, on construction,
+    /// by a random fraction in the range `[0, deadline_jitter)` of the time remaining
+    /// until the deadline. The deadline is never moved later, so the operation
+    /// never runs longer than requested.
+    ///
+    /// For example, `start_timeout(Duration::from_secs(60))` with `deadline_jitter(0.05)`
+    /// results in an effective timeout between 57 and 60 seconds.
+    ///
+    /// This value is clamped to the interval `[0, 1]`.
+    /// If `deadline_jitter` is `<= 0` or `NaN` (the default), the deadline is not changed.
+    /// Has no effect on [`Self::start_unlimited()`].
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let ease_off = Options::new()
+    ///     .deadline_jitter(0.05)
+    ///     .start_timeout::<()>(Duration::from_secs(60));
+    ///
+    /// let timeout = ease_off.deadline().unwrap() - ease_off.started_at();
+    /// assert!(timeout > Duration::from_secs(57) && timeout <= Duration::from_secs(60));
+    /// ```
+    #[inline(always)]
+    pub const fn deadline_jitter(self, deadline_jitter: f32) -> Self {
+        Self {
+            deadline_jitter,
+            ..self
+        }
+    }
+
+    /// Get the maximum jitter factor applied to the deadline.
+    ///
+    /// See [`Self::deadline_jitter()`] for details.
+    #[inline(always)]
+    pub const fn get_deadline_jitter(&self) -> f32 {
+        self.deadline_jitter
+    }
+
+    /// If `true`, time spent sleeping between attempts does not count toward the deadline.
+    ///
+    /// By default, the deadline bounds the total wall-clock time, including the backoff sleeps.
+    /// With this set, it instead bounds the time spent actually doing work, i.e. in attempts
+    /// (and in the code between them), as some SLAs require: the deadline is extended by the
+    /// total time slept so far, giving the operation `timeout + total slept` of wall-clock time.
+    ///
+    /// The sleep before an attempt does not count either, so an attempt is made if
+    /// the deadline has not elapsed when it is scheduled, however long the sleep before it.
+    /// Time spent [paused][EaseOff::pause()] is excluded as usual.
+    ///
+    /// [`EaseOff::deadline()`] still returns the deadline as set, without any extension.
+    ///
+    /// ### Note: Per-Attempt Timeouts
+    /// The extended deadline is used wherever the deadline applies to an attempt in progress,
+    /// e.g. [`TryAsync::enforce_deadline_with()`] cancels an attempt once the time left
+    /// for work runs out, not when the original deadline passes.
+    ///
+    /// A timeout for a single attempt, e.g. [`TryAsync::timeout_after()`],
+    /// is unaffected, as no sleep happens during an attempt. The attempt is cancelled
+    /// when its timeout or the extended deadline elapses, whichever is first.
+    ///
+    /// ### Warning: Unbounded Wall-Clock Time
+    /// If attempts fail quickly, little time counts toward the deadline, so the operation
+    /// may keep retrying, at up to [`max_delay`][Self::max_delay] apart,
+    /// for many times longer than the timeout. Bound the total time with an outer deadline
+    /// if that would be a problem, e.g. by checking [`EaseOff::started_at()`] in the loop.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(50))
+    ///     .multiplier(1.0)
+    ///     .jitter(0.0)
+    ///     .deadline_excludes_sleep(true)
+    ///     .start_timeout(Duration::from_millis(60));
+    ///
+    /// for _ in 0..4 {
+    ///     let result = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|e| e.is_maybe_retryable());
+    ///     assert_eq!(result, Ok(None));
+    /// }
+    ///
+    /// // The first retry is immediate, then each retry slept for 50 milliseconds.
+    /// assert!(ease_off.started_at().elapsed() >= Duration::from_millis(100));
+    ///
+    /// // This attempt uses up the remaining time for work.
+    /// let result = ease_off
+    ///     .try_blocking(|| {
+    ///         std::thread::sleep(Duration::from_millis(60));
+    ///         Err::<(), _>("slow")
+    ///     })
+    ///     .or_retry_if(|e| e.is_maybe_retryable());
+    /// assert_eq!(result, Ok(None));
+    ///
+    /// let result = ease_off
+    ///     .try_blocking(|| -> Result<(), _> { unreachable!("deadline should have elapsed") })
+    ///     .or_retry_if(|e| e.is_maybe_retryable());
+    /// assert_eq!(result, Err("slow"));
+    /// ```
+    ///
+    /// [`TryAsync::enforce_deadline_with()`]: crate::futures::TryAsync::enforce_deadline_with()
+    /// [`TryAsync::timeout_after()`]: crate::futures::TryAsync::timeout_after()
+    #[inline(always)]
+    pub const fn deadline_excludes_sleep(self, deadline_excludes_sleep: bool) -> Self {
+        Self {
+            deadline_excludes_sleep,
+            ..self
+        }
+    }
+
+    /// Get whether time spent sleeping between attempts counts toward the deadline.
+    ///
+    /// See [`Self::deadline_excludes_sleep()`] for details.
+    #[inline(always)]
+    pub const fn get_deadline_excludes_sleep(&self) -> bool {
+        self.deadline_excludes_sleep
+    }
+
+    /// Set the maximum fraction by which to randomly perturb the
+    /// [`initial_delay`][Self::initial_delay] of each [`EaseOff`].
+    ///
+    /// If set, each `EaseOff` picks its own initial delay once, on construction, uniformly
+    /// from `[initial_delay * (1 - base_spread), initial_delay * (1 + base_spread)]`.
+    /// Since every later delay is calculated from the initial delay, the entire schedule
+    /// is scaled, so instances started from the same options at the same time are shifted
+    /// relative to each other from the first delay until they reach
+    /// [`max_delay`][Self::max_delay], which is not perturbed.
+    /// [Overrides][Self::delay_override] and [`fast_first_retry`][Self::fast_first_retry]
+    /// are not perturbed either. The perturbed value is reflected in the options of
+    /// [`EaseOff::core()`].
+    ///
+    /// ### Interaction with Jitter
+    /// [`jitter`][Self::jitter] and [`initial_jitter`][Self::initial_jitter] are still applied
+    /// to each delay as usual, on top of the perturbed schedule. Jitter picks a new random
+    /// amount for every attempt, while the spread is picked once; the two may be combined,
+    /// or the spread used on its own (with jitter set to zero) to keep the gaps between the
+    /// attempts of each instance predictable, while still decorrelating instances.
+    ///
+    /// This value is clamped to the interval `[0, 1]`.
+    /// If `base_spread` is `<= 0` or `NaN` (the default), the initial delay is not changed.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// const OPTIONS: Options = Options::new()
+    ///     .initial_delay(Duration::from_millis(100))
+    ///     .base_spread(0.5);
+    ///
+    /// // Somewhere between 50ms and 150ms, fixed for the lifetime of this instance.
+    /// let ease_off = OPTIONS.start_unlimited::<()>();
+    /// let initial_delay = ease_off.core().options().get_initial_delay();
+    ///
+    /// assert!(initial_delay >= Duration::from_millis(49));
+    /// assert!(initial_delay <= Duration::from_millis(151));
+    /// ```
+    #[inline(always)]
+    pub const fn base_spread(self, base_spread: f32) -> Self {
+        Self {
+            base_spread,
+            ..self
+        }
+    }
+
+    /// Get the maximum fraction by which the initial delay is perturbed.
+    ///
+    /// See [`Self::base_spread()`] for details.
+    #[inline(always)]
+    pub const fn get_base_spread(&self) -> f32 {
+        self.base_spread
+    }
+
+    /// Set a soft deadline, relative to when the [`EaseOff`] is started.
+    ///
+    /// Crossing the soft deadline calls the function set with [`EaseOff::on_soft_deadline()`],
+    /// but does not stop retries; see that method for details.
+    /// Unlike the deadline, the soft deadline is not jittered.
+    ///
+    /// Has no effect if it is at or after the deadline, as retries stop at the deadline.
+    #[inline(always)]
+    pub const fn soft_deadline(self, soft_deadline: Duration) -> Self {
+        Self {
+            soft_deadline: Some(soft_deadline),
+            ..self
+        }
+    }
+
+    /// Get the soft deadline, if set.
+    ///
+    /// See [`Self::soft_deadline()`] for details.
+    #[inline(always)]
+    pub const fn get_soft_deadline(&self) -> Option<Duration> {
+        self.soft_deadline
+    }
+
+    /// Round each delay up to the nearest multiple of `quantum`, e.g. 10 milliseconds.
+    ///
+    /// Many platforms wake timers at a coarse granularity anyway, so sub-millisecond precision
+    /// in the backoff is illusory. Rounding to a multiple also makes schedules more predictable,
+    /// and lets timers for nearby delays be coalesced into fewer wakeups.
+    ///
+    /// Quantization is applied last, after [jitter][Self::jitter] and any
+    /// [overrides][Self::delay_override], so a delay may exceed
+    /// [`max_delay`][Self::max_delay] by less than `quantum`. A delay of zero,
+    /// e.g. for the first attempt, stays zero. A `quantum` of zero has no effect.
+    ///
+    /// Rounding up may push a retry past the deadline, in which case it is treated like any
+    /// other retry scheduled after the deadline, and [`Error::TimedOut`] is returned instead.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let core = Options::new()
+    ///     .initial_delay(Duration::from_millis(150))
+    ///     .jitter(0.25)
+    ///     .quantize_delay(Duration::from_millis(100))
+    ///     .into_core();
+    ///
+    /// let now = Instant::now();
+    ///
+    /// // The delay before jitter is 300ms, so after jitter it is in `(225ms, 300ms]`.
+    /// let retry_at = core.nth_retry_at(2, now, None, &mut rand::thread_rng()).unwrap();
+    /// assert_eq!(retry_at, Some(now + Duration::from_millis(300)));
+    /// ```
+    ///
+    /// [`Error::TimedOut`]: crate::Error::TimedOut
+    #[inline(always)]
+    pub const fn quantize_delay(self, quantum: Duration) -> Self {
+        Self {
+            quantize_delay: Some(quantum),
+            ..self
+        }
+    }
+
+    /// Get the duration that delays are rounded up to a multiple of, if set.
+    ///
+    /// See [`Self::quantize_delay()`] for details.
+    #[inline(always)]
+    pub const fn get_quantize_delay(&self) -> Option<Duration> {
+        self.quantize_delay
+    }
+
+    /// Give up after `max` consecutive attempts fail with a timeout, even if the deadline
+    /// has not elapsed.
+    ///
+    /// An operation that keeps timing out is a stronger signal of a wedged dependency
+    /// than intermittent failures, so this allows giving up on it quickly.
+    ///
+    /// An attempt is considered to have timed out if [`RetryableError::is_timeout()`] returns
+    /// `true` for its error (checked by [`ResultWrapper::or_retry()`]), or if the closure passed
+    /// to [`ResultWrapper::timeout_if()`] does. Any other outcome resets the count.
+    ///
+    /// Once the count reaches `max`, the error is converted to [`Error::TooManyTimeouts`]
+    /// and returned as fatal.
+    ///
+    /// A `max` of `0` is treated as `1`.
+    ///
+    /// [`RetryableError::is_timeout()`]: crate::RetryableError::is_timeout
+    /// [`ResultWrapper::or_retry()`]: crate::ResultWrapper::or_retry
+    /// [`ResultWrapper::timeout_if()`]: crate::ResultWrapper::timeout_if
+    /// [`Error::TooManyTimeouts`]: crate::Error::TooManyTimeouts
+    #[inline(always)]
+    pub const fn max_consecutive_timeouts(self, max: u32) -> Self {
+        Self {
+            max_consecutive_timeouts: Some(max),
+            ..self
+        }
+    }
+
+    /// Get the maximum number of consecutive timeouts, if set.
+    ///
+    /// See [`Self::max_consecutive_timeouts()`] for details.
+    #[inline(always)]
+    pub const fn get_max_consecutive_timeouts(&self) -> Option<u32> {
+        self.max_consecutive_timeouts
+    }
+
+    /// Give up once `max` distinct kinds of error have been seen, even if the deadline
+    /// has not elapsed.
+    ///
+    /// A retry loop that keeps seeing _different_ errors, rather than the same one repeatedly,
+    /// may indicate systemic instability which warrants escalation rather than more retries.
+    ///
+    /// What counts as a distinct kind of error is decided by the key extractor set with
+    /// [`EaseOff::distinct_errors_by()`]; **without one, this option has no effect**.
+    /// The key may be any type implementing [`Hash`][std::hash::Hash]; only its hash is kept,
+    /// so the set of seen keys is bounded to `max` hashes (8 bytes each).
+    ///
+    /// Once the `max`th distinct key is seen, the error is converted to
+    /// [`Error::TooManyDistinctErrors`] and returned as fatal. Errors already determined
+    /// to be fatal are not counted. The seen keys are forgotten along with the number
+    /// of attempts after a success (see [`Self::reset_on_success()`]).
+    ///
+    /// A `max` of `0` is treated as `1`.
+    ///
+    /// ```rust
+    /// use ease_off::{EaseOff, Error, Options};
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .max_distinct_errors(3)
+    ///     .start_unlimited()
+    ///     // Only consider the kind of error, ignoring the message.
+    ///     .distinct_errors_by(|e: &(&str, &str)| e.0);
+    ///
+    /// let errors = [
+    ///     ("dns", "lookup failed"),
+    ///     ("dns", "lookup failed again"),
+    ///     ("tls", "handshake failed"),
+    ///     ("http", "502 Bad Gateway"),
+    /// ];
+    ///
+    /// for (i, error) in errors.into_iter().enumerate() {
+    ///     let result = ease_off
+    ///         .try_blocking(|| Err::<(), _>(error))
+    ///         .inspect_err(|e| {
+    ///             assert_eq!(i == 3, matches!(e, Error::TooManyDistinctErrors(_)));
+    ///         })
+    ///         .or_retry_if(|_e| true);
+    ///
+    ///     assert_eq!(result.is_err(), i == 3);
+    /// }
+    /// ```
+    ///
+    /// [`EaseOff::distinct_errors_by()`]: crate::EaseOff::distinct_errors_by
+    /// [`Error::TooManyDistinctErrors`]: crate::Error::TooManyDistinctErrors
+    #[inline(always)]
+    pub const fn max_distinct_errors(self, max: u32) -> Self {
+        Self {
+            max_distinct_errors: Some(max),
+            ..self
+        }
+    }
+
+    /// Get the maximum number of distinct errors, if set.
+    ///
+    /// See [`Self::max_distinct_errors()`] for details.
+    #[inline(always)]
+    pub const fn get_max_distinct_errors(&self) -> Option<u32> {
+        self.max_distinct_errors
+    }
+
+    /// Cap every history buffer kept by an [`EaseOff`] at `capacity` entries.
+    ///
+    /// This is one knob for the memory used by the observability features of long-lived
+    /// instances, whatever limits they were enabled with:
+    ///
+    /// * the errors kept by [`EaseOff::keep_recent_errors()`];
+    /// * the delays recorded by [`EaseOff::with_history()`].
+    ///
+    /// Each buffer keeps at most the smaller of its own limit and `capacity` entries.
+    /// Once a buffer is full, the oldest entry is dropped to make room for each new one.
+    /// A `capacity` of `0` disables all history.
+    ///
+    /// The keys seen for [`Self::max_distinct_errors()`] are not affected, as they are already
+    /// bounded by that option, and dropping any would stop the limit being reached.
+    ///
+    /// Not set (no cap beyond each buffer's own limit) by default.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let mut ease_off = Options::new()
+    ///     .initial_delay(Duration::from_millis(1))
+    ///     .jitter(0.0)
+    ///     .history_capacity(2)
+    ///     .start_unlimited()
+    ///     .with_history(100);
+    ///
+    /// for _ in 0..5 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// // Only the two most recent delays are kept.
+    /// assert_eq!(*ease_off.realized_delays(), [2, 4].map(Duration::from_millis));
+    /// assert_eq!(ease_off.delay_summary().count, 3);
+    /// ```
+    ///
+    /// [`EaseOff::keep_recent_errors()`]: crate::EaseOff::keep_recent_errors
+    /// [`EaseOff::with_history()`]: crate::EaseOff::with_history
+    #[inline(always)]
+    pub const fn history_capacity(self, capacity: usize) -> Self {
+        Self {
+            history_capacity: Some(capacity),
+            ..self
+        }
+    }
+
+    /// Get the capacity of history buffers, if set.
+    ///
+    /// See [`Self::history_capacity()`] for details.
+    #[inline(always)]
+    pub const fn get_history_capacity(&self) -> Option<usize> {
+        self.history_capacity
+    }
+
+    /// If `true`, emit a [`tracing`] warning when an [`EaseOff`] is started from these options
+    /// with [`jitter`][Self::jitter] disabled (`<= 0` or `NaN`).
+    ///
+    /// Without jitter, clients which fail at the same time will all retry at the same time,
+    /// creating the [thundering herd problem]. This is opt-in (off by default) so intentionally
+    /// synchronized configurations, e.g. [`Self::deterministic()`] for load testing,
+    /// are not reported.
+    ///
+    /// The warning is emitted each time an `EaseOff` is started; it is not emitted by
+    /// [`Self::into_core()`], which is a `const fn`.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::Options;
+    ///
+    /// let options = Options::new().jitter(0.0).warn_on_thundering_herd(true);
+    ///
+    /// // Logs a warning if a `tracing` subscriber is installed.
+    /// let _ease_off = options.start_timeout::<()>(Duration::from_secs(30));
+    /// ```
+    ///
+    /// [`tracing`]: https://docs.rs/tracing
+    /// [`EaseOff`]: crate::EaseOff
+    /// [thundering herd problem]: https://en.wikipedia.org/wiki/Thundering_herd_problem
+    #[cfg(feature = "tracing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+    #[inline(always)]
+    pub const fn warn_on_thundering_herd(self, warn_on_thundering_herd: bool) -> Self {
+        Self {
+            warn_on_thundering_herd,
+            ..self
+        }
+    }
+
+    /// Get whether a warning is emitted for options with jitter disabled.
+    ///
+    /// See [`Self::warn_on_thundering_herd()`] for details.
+    #[cfg(feature = "tracing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+    #[inline(always)]
+    pub const fn get_warn_on_thundering_herd(&self) -> bool {
+        self.warn_on_thundering_herd
+    }
+
+    /// Use a hand-crafted list of delays instead of the exponential formula.
+    ///
+    /// The delay for attempt `n >= 1` (as passed to [`EaseOffCore::nth_retry_at()`]) is
+    /// `delays[n - 1]`. Once the list is exhausted, **the last delay repeats** for every
+    /// further attempt; to stop retrying instead, bound the number of attempts or set a deadline.
+    ///
+    /// [`EaseOff`] retries the first failure immediately (attempt `0`), so `delays[0]`
+    /// is the delay before the _second_ retry:
+    ///
+    /// | Attempt | `EaseOff` retry | Delay                           |
+    /// |---------|-----------------|---------------------------------|
+    /// | 0       | 1st             | none                            |
+    /// | 1       | 2nd             | `delays[0]`                     |
+    /// | 2       | 3rd             | `delays[1]`                     |
+    /// | `n`     | `n + 1`th       | `delays[n - 1]`, or the last    |
+    ///
+    /// This replaces [`initial_delay`][Self::initial_delay] (except before the first attempt;
+    /// see [`initial_jitter`][Self::initial_jitter]), [`multiplier`][Self::multiplier] and
+    /// [`fast_first_retry`][Self::fast_first_retry]. [Overrides][Self::delay_override]
+    /// still take precedence, delays are still capped at [`max_delay`][Self::max_delay],
+    /// and [jitter][Self::jitter] still applies if configured.
+    ///
+    /// An empty list is ignored. Calling this again replaces the previous schedule.
+    ///
+    /// Like [`Self::with_warmup()`], the list is taken by `'static` reference so that
+    /// `Options` stays usable in `const`s, and is not represented in `OptionsConfig`
+    /// or `Options::from_spec()`.
+    ///
+    /// [`EaseOffCore::nth_retry_at()`]: crate::core::EaseOffCore::nth_retry_at()
+    /// [`EaseOff`]: crate::EaseOff
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// const DELAYS: &[Duration] = &[
+    ///     Duration::from_millis(100),
+    ///     Duration::from_millis(500),
+    ///     Duration::from_secs(2),
+    ///     Duration::from_secs(10),
+    /// ];
+    ///
+    /// let core = Options::new().jitter(0.0).fixed_schedule(DELAYS).into_core();
+    ///
+    /// let now = Instant::now();
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let delays: Vec<_> = (1..7)
+    ///     .map(|n| core.nth_retry_at(n, now, None, &mut rng).unwrap().unwrap() - now)
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     delays,
+    ///     [100, 500, 2_000, 10_000, 10_000, 10_000].map(Duration::from_millis),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn fixed_schedule(self, delays: &'static [Duration]) -> Self {
+        Self {
+            fixed_schedule: if delays.is_empty() {
+                None
+            } else {
+                Some(delays)
+            },
+            ..self
+        }
+    }
+
+    /// Get the fixed schedule of delays, if set.
+    ///
+    /// See [`Self::fixed_schedule()`] for details.
+    #[inline(always)]
+    pub const fn get_fixed_schedule(&self) -> Option<&'static [Duration]> {
+        self.fixed_schedule
+    }
+
+    /// Use these options for the first `warmup_attempts` attempts, then `steady` for the rest.
+    ///
+    /// This models staged backoff, e.g. gentler retries right after startup while
+    /// a connection pool is still warming up, and the usual schedule afterwards.
+    ///
+    /// The delay before attempt `n` is calculated entirely by `self` if
+    /// `n < warmup_attempts`, and entirely by `steady` otherwise, including
+    /// [`max_delay`][Self::max_delay], [jitter][Self::jitter] and any
+    /// [overrides][Self::delay_override]. `steady` is not restarted at the switch point:
+    /// attempt `n` gets the same delay it would if `steady` had been used from the start.
+    ///
+    /// Options that apply to the [`EaseOff`] as a whole rather than to individual attempts,
+    /// i.e. [`allow_zero_attempts`][Self::allow_zero_attempts],
+    /// [`reset_on_success`][Self::reset_on_success],
+    /// [`deadline_jitter`][Self::deadline_jitter],
+    /// [`deadline_excludes_sleep`][Self::deadline_excludes_sleep],
+    /// [`base_spread`][Self::base_spread],
+    /// [`soft_deadline`][Self::soft_deadline],
+    /// [`quantize_delay`][Self::quantize_delay],
+    /// [`max_consecutive_timeouts`][Self::max_consecutive_timeouts],
+    /// [`max_distinct_errors`][Self::max_distinct_errors] and
+    /// [`history_capacity`][Self::history_capacity],
+    /// are always taken from `self`.
+    ///
+    /// `steady` may itself have a warmup, to add more stages.
+    /// Calling this again replaces the previous warmup.
+    ///
+    /// `steady` is taken by `'static` reference so that `Options` stays usable in `const`s.
+    /// Options only known at runtime (e.g. loaded from configuration) may be leaked
+    /// with [`Box::leak()`], which is fine if done once at startup.
+    /// The warmup is not represented in `OptionsConfig` or `Options::from_spec()`.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// const STEADY: Options = Options::new()
+    ///     .jitter(0.0)
+    ///     .initial_delay(Duration::from_secs(1))
+    ///     .max_delay(Duration::from_secs(30));
+    ///
+    /// const OPTIONS: Options = Options::new()
+    ///     .jitter(0.0)
+    ///     .initial_delay(Duration::from_millis(10))
+    ///     .multiplier(1.0)
+    ///     .with_warmup(3, &STEADY);
+    ///
+    /// let core = OPTIONS.into_core();
+    ///
+    /// let now = Instant::now();
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let delays: Vec<_> = (1..6)
+    ///     .map(|n| core.nth_retry_at(n, now, None, &mut rng).unwrap().unwrap() - now)
+    ///     .collect();
+    ///
+    /// // Attempts 1 and 2 use the warmup schedule, attempts 3 onward the steady schedule.
+    /// assert_eq!(
+    ///     delays,
+    ///     [10, 10, 4_000, 8_000, 16_000].map(Duration::from_millis),
+    /// );
+    /// ```
+    #[inline(always)]
+    pub const fn with_warmup(self, warmup_attempts: u32, steady: &'static Options) -> Self {
+        Self {
+            warmup: Some((warmup_attempts, steady)),
+            ..self
+        }
+    }
+
+    /// Get the number of warmup attempts and the options used after them, if set.
+    ///
+    /// See [`Self::with_warmup()`] for details.
+    #[inline(always)]
+    pub const fn get_warmup(&self) -> Option<(u32, &'static Options)> {
+        self.warmup
+    }
+
+    /// Check that the values are in range, for options parsed from configuration.
+    ///
+    /// Out-of-range values are otherwise clamped or ignored as documented on each method,
+    /// but that is more likely to be a mistake in a configuration file than in code.
+    #[cfg(any(feature = "serde", feature = "spec"))]
+    pub(crate) fn validate(&self) -> Result<(), InvalidOptions> {
+        if !(self.multiplier.is_finite() && self.multiplier > 0f32) {
+            return Err(InvalidOptions::Multiplier(self.multiplier));
+        }
+
+        for (field, value) in [
+            ("jitter", self.jitter),
+            ("initial_jitter", self.initial_jitter),
+            ("deadline_jitter", self.deadline_jitter),
+            ("base_spread", self.base_spread),
+        ] {
+            if !(0f32..=1f32).contains(&value) {
+                return Err(InvalidOptions::Jitter { field, value });
+            }
+        }
+
+        if let Some(value) = self.initial_immediate_probability {
+            if !(0f32..=1f32).contains(&value) {
+                return Err(InvalidOptions::Jitter {
+                    field: "initial_immediate_probability",
+                    value,
+                });
+            }
+        }
+
+        if self.initial_delay > self.max_delay {
+            return Err(InvalidOptions::InitialDelay {
+                initial_delay: self.initial_delay,
+                max_delay: self.max_delay,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Convert this `Options` into an [`EaseOffCore`].
     #[inline(always)]
     pub const fn into_core(self) -> EaseOffCore {
@@ -165,50 +1261,68 @@ impl Options {
     ///
     /// The operation will be retried until it succeeds, or a non-retryable error occurs.
     pub fn start_unlimited<E>(&self) -> EaseOff<E> {
-        self.start(Instant::now(), None)
+        self.start(clock::now(), None)
     }
 
     /// Begin backing off, limited by the given timeout.
     ///
     /// Always makes one attempt, even if the timeout is zero or has elapsed
-    /// by the time the first attempt is made.
+    /// by the time the first attempt is made, unless zero attempts are
+    /// [allowed][Self::allow_zero_attempts()].
+    ///
+    /// If the deadline (`Instant::now() + timeout`) would overflow [`Instant`],
+    /// it saturates to the latest representable `Instant` instead,
+    /// so a finite timeout never silently becomes unlimited:
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::EaseOff;
+    ///
+    /// let ease_off = EaseOff::<()>::start_timeout(Duration::MAX);
+    ///
+    /// let deadline = ease_off.deadline().expect("deadline should be set");
+    /// assert!(deadline > ease_off.started_at() + Duration::from_secs(100 * 365 * 24 * 60 * 60));
+    /// ```
     ///
     /// See also:
     /// * [`Self::start_timeout_opt()`] for a conditional timeout.
     /// * [`Self::start_deadline()`] to specify an [`Instant`] as a deadline.
     pub fn start_timeout<E>(&self, timeout: Duration) -> EaseOff<E> {
-        let started_at = Instant::now();
-        self.start(started_at, started_at.checked_add(timeout))
+        let started_at = clock::now();
+        self.start(started_at, Some(clock::saturating_add(started_at, timeout)))
     }
 
     /// Begin backing off, limited by the given optional timeout.
     ///
     /// If `timeout` is `None`, this is equivalent to [`Self::start_unlimited()`].
+    /// If the deadline would overflow, it saturates as described for [`Self::start_timeout()`].
     ///
     /// Always makes one attempt, even if the timeout is zero or has elapsed
-    /// by the time the first attempt is made.
+    /// by the time the first attempt is made, unless zero attempts are
+    /// [allowed][Self::allow_zero_attempts()].
     ///
     /// See also:
     /// * [`Self::start_timeout()`] for a non-conditional timeout.
     /// * [`Self::start_deadline_opt()`] to specify an optional [`Instant`] as a deadline.
     pub fn start_timeout_opt<E>(&self, timeout: Option<Duration>) -> EaseOff<E> {
-        let started_at = Instant::now();
+        let started_at = clock::now();
         self.start(
             started_at,
-            timeout.and_then(|timeout| started_at.checked_add(timeout)),
+            timeout.map(|timeout| clock::saturating_add(started_at, timeout)),
         )
     }
 
     /// Begin backing off, halting attempts at the given deadline.
     ///
     /// Always makes one attempt, even if the deadline is `<= Instant::now()` or has elapsed
-    /// by the time the first attempt is made.
+    /// by the time the first attempt is made, unless zero attempts are
+    /// [allowed][Self::allow_zero_attempts()].
     ///
     /// See also:
     /// * [`Self::start_deadline_opt()`] for a conditional deadline.
     /// * [`Self::start_timeout()`] to specify a [`Duration`] as a timeout.
     pub fn start_deadline<E>(&self, deadline: Instant) -> EaseOff<E> {
-        self.start(Instant::now(), Some(deadline))
+        self.start(clock::now(), Some(deadline))
     }
 
     /// Begin backing off, halting attempts at the given deadline.
@@ -216,24 +1330,151 @@ impl Options {
     /// If `deadline` is `None`, this is equivalent to [`Self::start_unlimited()`].
     ///
     /// Always makes one attempt, even if the deadline is `<= Instant::now()` or has elapsed
-    /// by the time the first attempt is made.
+    /// by the time the first attempt is made, unless zero attempts are
+    /// [allowed][Self::allow_zero_attempts()].
     ///
     /// See also:
     /// * [`Self::start_deadline()`] for a non-conditional deadline.
     /// * [`Self::start_timeout_opt()`] to specify an optional [`Duration`] as a timeout.
     pub fn start_deadline_opt<E>(&self, deadline: Option<Instant>) -> EaseOff<E> {
-        self.start(Instant::now(), deadline)
+        self.start(clock::now(), deadline)
     }
 
-    fn start<E>(&self, started_at: Instant, deadline: Option<Instant>) -> EaseOff<E> {
-        EaseOff {
-            core: EaseOffCore::new(self.clone()),
-            started_at,
-            deadline,
-            num_attempts: Saturating(0),
-            last_error: None,
-            next_retry_at: None,
+    /// Like [`Self::start_timeout()`], but with `timeout` measured from `started_at`,
+    /// which may be earlier than `Instant::now()`.
+    ///
+    /// This is for loops that begin before the `EaseOff` is constructed, so that setup time
+    /// (e.g. acquiring a lock) counts toward the deadline and is included in
+    /// [`EaseOff::started_at()`] and the elapsed time reported by [`EaseOff::summary()`].
+    ///
+    /// A `started_at` later than `Instant::now()` is clamped to `Instant::now()`.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// let started_at = Instant::now();
+    /// // Setup which counts toward the timeout.
+    /// std::thread::sleep(Duration::from_millis(10));
+    ///
+    /// let ease_off = Options::new().start_timeout_from::<()>(started_at, Duration::from_secs(1));
+    ///
+    /// assert_eq!(ease_off.started_at(), started_at);
+    /// assert_eq!(ease_off.deadline(), Some(started_at + Duration::from_secs(1)));
+    /// assert!(ease_off.summary().elapsed >= Duration::from_millis(10));
+    ///
+    /// // Starting in the future is not allowed.
+    /// let later = Instant::now() + Duration::from_secs(60);
+    /// let ease_off = Options::new().start_timeout_from::<()>(later, Duration::from_secs(1));
+    /// assert!(ease_off.started_at() < later);
+    /// ```
+    pub fn start_timeout_from<E>(&self, started_at: Instant, timeout: Duration) -> EaseOff<E> {
+        let started_at = cmp::min(started_at, clock::now());
+        self.start(started_at, Some(clock::saturating_add(started_at, timeout)))
+    }
+
+    /// Like [`Self::start_deadline()`], but starting at `started_at`,
+    /// which may be earlier than `Instant::now()`.
+    ///
+    /// See [`Self::start_timeout_from()`] for details.
+    pub fn start_deadline_from<E>(&self, started_at: Instant, deadline: Instant) -> EaseOff<E> {
+        self.start(cmp::min(started_at, clock::now()), Some(deadline))
+    }
+
+    /// Begin backing off for an inner operation, nested within an outer [`EaseOff`].
+    ///
+    /// This supports multi-tier retries: an inner `EaseOff` retries a sub-operation quickly,
+    /// and if it gives up, the whole group counts as a single failed attempt
+    /// of the outer `EaseOff`, which then backs off for longer.
+    ///
+    /// The deadline of the returned instance is the earlier of `Instant::now() + timeout`
+    /// and `outer_deadline` (typically [`EaseOff::deadline()`] of the outer instance),
+    /// so the inner retries never outlive the outer deadline.
+    /// If both are `None`, this is equivalent to [`Self::start_unlimited()`].
+    ///
+    /// Start a new inner instance for each outer attempt, so each group of inner attempts
+    /// starts from the initial delay.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{Options, RetryableError};
+    ///
+    /// #[derive(Debug)]
+    /// struct Unavailable;
+    ///
+    /// impl RetryableError for Unavailable {
+    ///     fn can_retry(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// const INNER: Options = Options::new().initial_delay(Duration::from_millis(1));
+    /// const OUTER: Options = Options::new().initial_delay(Duration::from_millis(10));
+    ///
+    /// let mut calls = 0;
+    /// let mut outer = OUTER.start_timeout(Duration::from_secs(10));
+    ///
+    /// let value = loop {
+    ///     let outer_deadline = outer.deadline();
+    ///
+    ///     let Some(value) = outer
+    ///         .try_blocking(|| {
+    ///             // Retry quickly for up to 20ms...
+    ///             let mut inner =
+    ///                 INNER.start_within(Some(Duration::from_millis(20)), outer_deadline);
+    ///
+    ///             ease_off::retry!(inner, {
+    ///                 calls += 1;
+    ///
+    ///                 if calls < 10 {
+    ///                     Err(Unavailable)
+    ///                 } else {
+    ///                     Ok(calls)
+    ///                 }
+    ///             })
+    ///         })
+    ///         // ...then back off for longer if the inner retries gave up.
+    ///         .or_retry()?
+    ///     else {
+    ///         continue;
+    ///     };
+    ///
+    ///     break value;
+    /// };
+    ///
+    /// assert_eq!(value, 10);
+    /// # Ok::<_, Unavailable>(())
+    /// ```
+    pub fn start_within<E>(
+        &self,
+        timeout: Option<Duration>,
+        outer_deadline: Option<Instant>,
+    ) -> EaseOff<E> {
+        let started_at = clock::now();
+
+        let deadline = timeout.map(|timeout| clock::saturating_add(started_at, timeout));
+
+        // `None` compares less than `Some`, so we can't just use `cmp::min()`.
+        let deadline = match (deadline, outer_deadline) {
+            (Some(deadline), Some(outer_deadline)) => Some(deadline.min(outer_deadline)),
+            (deadline, outer_deadline) => deadline.or(outer_deadline),
+        };
+
+        self.start(started_at, deadline)
+    }
+
+    pub(crate) fn start<E>(&self, started_at: Instant, deadline: Option<Instant>) -> EaseOff<E> {
+        #[cfg(feature = "tracing")]
+        if self.warn_on_thundering_herd && (self.jitter.is_nan() || self.jitter <= 0f32) {
+            tracing::warn!(
+                multiplier = self.multiplier,
+                initial_delay = ?self.initial_delay,
+                "ease-off: jitter is disabled, so clients which fail together will retry together \
+                 (thundering herd); consider setting `Options::jitter()`"
+            );
         }
+
+        EaseOffCore::new(self.clone()).start(started_at, deadline)
     }
 }
 
@@ -244,3 +1485,42 @@ impl Default for Options {
         Self::DEFAULT
     }
 }
+
+/// Error returned when parsing [`Options`] from configuration with invalid values.
+///
+/// Returned when converting from an `OptionsConfig` (requires the `serde` feature)
+/// or parsing with `Options::from_spec()` (requires the `spec` feature).
+#[cfg(any(feature = "serde", feature = "spec"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "serde", feature = "spec"))))]
+#[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
+pub enum InvalidOptions {
+    /// `multiplier` was not a finite number greater than zero.
+    #[error("`multiplier` must be a finite number greater than zero, got {0}")]
+    Multiplier(f32),
+    /// `jitter`, `initial_jitter`, `deadline_jitter`, `base_spread`
+    /// or `initial_immediate_probability` was not in the interval `[0, 1]`.
+    #[error("`{field}` must be in the interval [0, 1], got {value}")]
+    Jitter {
+        /// The name of the invalid field.
+        field: &'static str,
+        /// The invalid value.
+        value: f32,
+    },
+    /// `initial_delay` was greater than `max_delay`.
+    #[error(
+        "`initial_delay` ({initial_delay:?}) must not be greater than `max_delay` ({max_delay:?})"
+    )]
+    InitialDelay {
+        /// The value of `initial_delay`.
+        initial_delay: Duration,
+        /// The value of `max_delay`.
+        max_delay: Duration,
+    },
+    /// More than [`Options::MAX_DELAY_OVERRIDES`] distinct attempts were overridden.
+    #[error(
+        "at most {} delay overrides may be set, got {0}",
+        Options::MAX_DELAY_OVERRIDES
+    )]
+    TooManyDelayOverrides(usize),
+}
@@ -0,0 +1,204 @@
+//! Retry [`reqwest`] requests with sensible defaults.
+//!
+//! This ties together the status classification and `Retry-After` handling of the
+//! [`http`][crate::http] module with async retries, for the common case of sending a request
+//! and retrying it until it succeeds, fails permanently, or the timeout elapses.
+//!
+//! ```rust,no_run
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), reqwest::Error> {
+//! use std::time::Duration;
+//!
+//! let client = reqwest::Client::new();
+//!
+//! let response = ease_off::reqwest::send(
+//!     client.get("http://example.com/api/status"),
+//!     Duration::from_secs(30),
+//! )
+//! .await?;
+//!
+//! // The final response is returned whatever its status, so check it as usual.
+//! let body = response.error_for_status()?.text().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Default Retry Policy
+//! * Responses with a status in [`RETRYABLE_STATUSES`] are retried;
+//!   all other responses, successful or not, are returned immediately.
+//! * If a retryable response has a `Retry-After` header, the next attempt waits until then
+//!   or for the backoff, whichever is later (see [`HttpError::retry_after_header()`]).
+//!   If that is after the deadline, the response is returned immediately instead.
+//! * Errors connecting to the server and timeouts (e.g. set with
+//!   [`RequestBuilder::timeout()`]) are retried; all other [`reqwest::Error`]s are returned
+//!   immediately. Timeouts count toward [`Options::max_consecutive_timeouts()`].
+//! * Once the timeout elapses, the last response or error is returned.
+//! * A request whose body cannot be cloned (i.e. a stream) is sent exactly once.
+//!
+//! [`RETRYABLE_STATUSES`]: crate::http::RETRYABLE_STATUSES
+
+use crate::http::{is_retryable_status, HttpError};
+use crate::{Options, RetryableError};
+use ::reqwest::header::RETRY_AFTER;
+use ::reqwest::{RequestBuilder, Response};
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+/// Send `request`, retrying it using [`Options::DEFAULT`] until `timeout` elapses.
+///
+/// See the [module documentation][self] for the retry policy.
+///
+/// ### Errors
+/// If the last attempt failed with an error instead of a response.
+/// Responses with an error status are returned as `Ok`.
+pub async fn send(
+    request: RequestBuilder,
+    timeout: Duration,
+) -> Result<Response, ::reqwest::Error> {
+    send_with_options(request, &Options::DEFAULT, timeout).await
+}
+
+/// Send `request`, retrying it using the given `options` until `timeout` elapses.
+///
+/// See the [module documentation][self] for the retry policy.
+///
+/// ### Errors
+/// If the last attempt failed with an error instead of a response.
+/// Responses with an error status are returned as `Ok`.
+pub async fn send_with_options(
+    request: RequestBuilder,
+    options: &Options,
+    timeout: Duration,
+) -> Result<Response, ::reqwest::Error> {
+    // A request with a streaming body cannot be retried.
+    if request.try_clone().is_none() {
+        return request.send().await;
+    }
+
+    let mut ease_off = options.start_timeout(timeout);
+    let deadline = ease_off.deadline();
+
+    loop {
+        let attempt = request
+            .try_clone()
+            .expect("BUG: request was cloneable before");
+
+        let res = ease_off
+            .try_async(send_once(attempt))
+            .await
+            .timeout_if(Failure::is_timeout)
+            .or_retry_with(|e| {
+                if !e.can_retry() {
+                    return ControlFlow::Break(());
+                }
+
+                match (e.inner().retry_at(), deadline) {
+                    // No point waiting if we'll give up before then.
+                    (Some(retry_at), Some(deadline)) if retry_at > deadline => {
+                        ControlFlow::Break(())
+                    }
+                    (retry_at, _) => ControlFlow::Continue(retry_at),
+                }
+            });
+
+        match res {
+            Ok(Some(response)) => return Ok(response),
+            Ok(None) => continue,
+            Err(Failure::Status(response, _)) => return Ok(response),
+            Err(Failure::Request(e)) => return Err(e),
+        }
+    }
+}
+
+async fn send_once(request: RequestBuilder) -> Result<Response, Failure> {
+    let response = request.send().await.map_err(Failure::Request)?;
+    let status = response.status().as_u16();
+
+    if !is_retryable_status(status) {
+        return Ok(response);
+    }
+
+    let mut error = HttpError::new(status);
+
+    if let Some(value) = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+    {
+        error = error.retry_after_header(value);
+    }
+
+    Err(Failure::Status(response, error))
+}
+
+/// A failed attempt, which may be retried.
+#[derive(Debug)]
+enum Failure {
+    Request(::reqwest::Error),
+    Status(Response, HttpError),
+}
+
+impl Failure {
+    fn retry_at(&self) -> Option<std::time::Instant> {
+        match self {
+            Self::Request(_) => None,
+            Self::Status(_, e) => e.retry_at,
+        }
+    }
+}
+
+impl RetryableError for Failure {
+    fn can_retry(&self) -> bool {
+        match self {
+            Self::Request(e) => e.is_connect() || e.is_timeout(),
+            Self::Status(_, e) => e.can_retry(),
+        }
+    }
+
+    fn is_timeout(&self) -> bool {
+        matches!(self, Self::Request(e) if e.is_timeout())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn timeouts_count_toward_max_consecutive_timeouts() {
+        // Accepts connections but never responds.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicU32::new(0));
+
+        std::thread::spawn({
+            let connections = connections.clone();
+            move || {
+                let mut streams = Vec::new();
+
+                for stream in listener.incoming() {
+                    connections.fetch_add(1, Ordering::SeqCst);
+                    streams.push(stream);
+                }
+            }
+        });
+
+        let request = ::reqwest::Client::new()
+            .get(format!("http://{addr}/"))
+            .timeout(Duration::from_millis(50));
+
+        let options = Options::new()
+            .initial_delay(Duration::from_millis(1))
+            .max_consecutive_timeouts(2);
+
+        let e = send_with_options(request, &options, Duration::from_secs(30))
+            .await
+            .unwrap_err();
+
+        assert!(e.is_timeout());
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,36 @@
+use rand::rngs::ThreadRng;
+use rand::RngCore;
+
+/// Defers calling [`rand::thread_rng()`] until randomness is actually needed,
+/// which is never the case if jitter is disabled.
+pub(crate) struct LazyRng(Option<ThreadRng>);
+
+impl LazyRng {
+    #[inline(always)]
+    pub(crate) fn new() -> Self {
+        Self(None)
+    }
+
+    #[inline(always)]
+    fn get(&mut self) -> &mut ThreadRng {
+        self.0.get_or_insert_with(rand::thread_rng)
+    }
+}
+
+impl RngCore for LazyRng {
+    fn next_u32(&mut self) -> u32 {
+        self.get().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.get().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.get().fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.get().try_fill_bytes(dest)
+    }
+}
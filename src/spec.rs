@@ -0,0 +1,197 @@
+use crate::{InvalidOptions, Options};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Error returned by [`Options::from_spec()`].
+#[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseSpecError {
+    /// An entry was not of the form `key=value`.
+    #[error("expected `key=value`, got {0:?}")]
+    Malformed(String),
+    /// An entry had a key which is not recognized.
+    #[error("unknown key {0:?}")]
+    UnknownKey(String),
+    /// A key was given more than once.
+    #[error("key `{0}` given more than once")]
+    DuplicateKey(&'static str),
+    /// A value could not be parsed.
+    #[error("invalid value for `{key}` ({value:?}): {reason}")]
+    InvalidValue {
+        /// The key of the invalid value.
+        key: &'static str,
+        /// The invalid value.
+        value: String,
+        /// Why the value could not be parsed.
+        reason: String,
+    },
+    /// The values were parsed, but are out of range.
+    #[error(transparent)]
+    Invalid(#[from] InvalidOptions),
+}
+
+const KEYS: [&str; 17] = [
+    "initial",
+    "max",
+    "mult",
+    "jitter",
+    "initial_jitter",
+    "initial_immediate",
+    "deadline_jitter",
+    "deadline_excludes_sleep",
+    "base_spread",
+    "soft_deadline",
+    "quantize",
+    "fast_first",
+    "zero_attempts",
+    "max_timeouts",
+    "max_distinct_errors",
+    "history_capacity",
+    "reset_on_success",
+];
+
+impl Options {
+    /// Parse `Options` from a compact, comma-separated specification,
+    /// e.g. for a command-line flag or environment variable.
+    ///
+    /// ```text
+    /// initial=150ms,mult=2.0,jitter=0.25,max=60s
+    /// ```
+    ///
+    /// ### Grammar
+    /// The spec is a list of `key=value` entries separated by commas.
+    /// Whitespace around keys and values is ignored, as are empty entries,
+    /// so the empty string parses to [`Options::DEFAULT`].
+    ///
+    /// Every key is optional and may be given at most once;
+    /// missing keys are taken from [`Options::DEFAULT`].
+    ///
+    /// | Key                       | Value              | Option                                       |
+    /// |---------------------------|--------------------|----------------------------------------------|
+    /// | `initial`                 | duration           | [`Options::initial_delay()`]                 |
+    /// | `max`                     | duration           | [`Options::max_delay()`]                     |
+    /// | `mult`                    | number             | [`Options::multiplier()`]                    |
+    /// | `jitter`                  | number in `[0, 1]` | [`Options::jitter()`]                        |
+    /// | `initial_jitter`          | number in `[0, 1]` | [`Options::initial_jitter()`]                |
+    /// | `initial_immediate`       | number in `[0, 1]` | [`Options::initial_immediate_probability()`] |
+    /// | `deadline_jitter`         | number in `[0, 1]` | [`Options::deadline_jitter()`]               |
+    /// | `deadline_excludes_sleep` | `true` or `false`  | [`Options::deadline_excludes_sleep()`]       |
+    /// | `base_spread`             | number in `[0, 1]` | [`Options::base_spread()`]                   |
+    /// | `soft_deadline`           | duration           | [`Options::soft_deadline()`]                 |
+    /// | `quantize`                | duration           | [`Options::quantize_delay()`]                |
+    /// | `fast_first`              | duration           | [`Options::fast_first_retry()`]              |
+    /// | `zero_attempts`           | `true` or `false`  | [`Options::allow_zero_attempts()`]           |
+    /// | `max_timeouts`            | integer            | [`Options::max_consecutive_timeouts()`]      |
+    /// | `max_distinct_errors`     | integer            | [`Options::max_distinct_errors()`]           |
+    /// | `history_capacity`        | integer            | [`Options::history_capacity()`]              |
+    /// | `reset_on_success`        | `true` or `false`  | [`Options::reset_on_success()`]              |
+    ///
+    /// Durations are written in [`humantime`] format, e.g. `150ms` or `1m30s`
+    /// (the value may not contain spaces or commas).
+    ///
+    /// Delay overrides are not supported; set them in code or use `OptionsConfig`
+    /// (requires the `serde` feature).
+    ///
+    /// Also available via [`FromStr`], so `"...".parse::<Options>()` works too.
+    ///
+    /// [`humantime`]: https://docs.rs/humantime
+    ///
+    /// ### Errors
+    /// If the spec is malformed, contains an unknown or duplicate key or an unparseable value,
+    /// or if the resulting values are out of range (see [`InvalidOptions`]).
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use ease_off::{Options, ParseSpecError};
+    ///
+    /// let options = Options::from_spec("initial=150ms, mult=2.0, jitter=0.25, max=60s").unwrap();
+    ///
+    /// assert_eq!(options.get_initial_delay(), Duration::from_millis(150));
+    /// assert_eq!(options.get_max_delay(), Duration::from_secs(60));
+    ///
+    /// assert!(matches!(
+    ///     Options::from_spec("initial=150ms,maximum=1s"),
+    ///     Err(ParseSpecError::UnknownKey(key)) if key == "maximum"
+    /// ));
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Self, ParseSpecError> {
+        let mut options = Options::DEFAULT;
+        let mut seen = [false; KEYS.len()];
+
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Some((key, value)) = entry.split_once('=') else {
+                return Err(ParseSpecError::Malformed(entry.to_string()));
+            };
+
+            let (key, value) = (key.trim(), value.trim());
+
+            let Some(i) = KEYS.iter().position(|&k| k == key) else {
+                return Err(ParseSpecError::UnknownKey(key.to_string()));
+            };
+
+            let key = KEYS[i];
+
+            if std::mem::replace(&mut seen[i], true) {
+                return Err(ParseSpecError::DuplicateKey(key));
+            }
+
+            options = match key {
+                "initial" => options.initial_delay(parse_duration(key, value)?),
+                "max" => options.max_delay(parse_duration(key, value)?),
+                "mult" => options.multiplier(parse_value(key, value)?),
+                "jitter" => options.jitter(parse_value(key, value)?),
+                "initial_jitter" => options.initial_jitter(parse_value(key, value)?),
+                "initial_immediate" => {
+                    options.initial_immediate_probability(parse_value(key, value)?)
+                }
+                "deadline_jitter" => options.deadline_jitter(parse_value(key, value)?),
+                "deadline_excludes_sleep" => {
+                    options.deadline_excludes_sleep(parse_value(key, value)?)
+                }
+                "base_spread" => options.base_spread(parse_value(key, value)?),
+                "soft_deadline" => options.soft_deadline(parse_duration(key, value)?),
+                "quantize" => options.quantize_delay(parse_duration(key, value)?),
+                "fast_first" => options.fast_first_retry(parse_duration(key, value)?),
+                "zero_attempts" => options.allow_zero_attempts(parse_value(key, value)?),
+                "max_timeouts" => options.max_consecutive_timeouts(parse_value(key, value)?),
+                "max_distinct_errors" => options.max_distinct_errors(parse_value(key, value)?),
+                "history_capacity" => options.history_capacity(parse_value(key, value)?),
+                "reset_on_success" => options.reset_on_success(parse_value(key, value)?),
+                _ => unreachable!("BUG: key {key:?} in `KEYS` but not handled"),
+            };
+        }
+
+        options.validate()?;
+
+        Ok(options)
+    }
+}
+
+impl FromStr for Options {
+    type Err = ParseSpecError;
+
+    /// See [`Options::from_spec()`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_spec(s)
+    }
+}
+
+fn parse_duration(key: &'static str, value: &str) -> Result<Duration, ParseSpecError> {
+    humantime::parse_duration(value).map_err(|e| invalid_value(key, value, e))
+}
+
+fn parse_value<T>(key: &'static str, value: &str) -> Result<T, ParseSpecError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    value.parse().map_err(|e| invalid_value(key, value, e))
+}
+
+fn invalid_value(key: &'static str, value: &str, reason: impl std::fmt::Display) -> ParseSpecError {
+    ParseSpecError::InvalidValue {
+        key,
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{clock, EaseOff, Options};
+use std::num::Saturating;
+use std::time::Duration;
+
+/// A snapshot of the progress of an [`EaseOff`], for resuming it later, possibly in another
+/// process. Returned by [`EaseOff::state()`] and consumed by [`Options::resume()`].
+///
+/// As an [`Instant`] is only meaningful within the process that created it,
+/// all times are captured as durations relative to when the snapshot was taken,
+/// and rebased onto the current time when resuming:
+///
+/// * The start time is preserved as the time [`elapsed`][Self::elapsed] so far.
+/// * The deadline, if set, is preserved as the time [`remaining`][Self::remaining] until it,
+///   so the time between taking the snapshot and resuming (e.g. while the process was down)
+///   does not count against it.
+///
+/// With the `serde` feature, this implements `Serialize` and `Deserialize`.
+/// Durations are written in [`humantime`] format, e.g. `"1s 500ms"`.
+///
+/// [`humantime`]: https://docs.rs/humantime
+/// [`Instant`]: std::time::Instant
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct EaseOffState {
+    /// The number of attempts made; see [`EaseOff::num_attempts()`].
+    pub attempts: u32,
+    /// The time elapsed since [`EaseOff::started_at()`], excluding time spent
+    /// [paused][EaseOff::pause()].
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub elapsed: Duration,
+    /// The time remaining until the [deadline][EaseOff::deadline()], if set.
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub remaining: Option<Duration>,
+    /// The total time spent waiting for backoff delays; see [`EaseOff::succeeded()`].
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub total_slept: Duration,
+}
+
+impl EaseOffState {
+    /// Create a state from values persisted by other means than `serde`.
+    pub fn new(attempts: u32, elapsed: Duration, remaining: Option<Duration>) -> Self {
+        Self {
+            attempts,
+            elapsed,
+            remaining,
+            total_slept: Duration::ZERO,
+        }
+    }
+}
+
+impl<E> EaseOff<E> {
+    /// Take a snapshot of the progress of this instance, to [resume][Options::resume()] later.
+    ///
+    /// Only the progress is captured; the [`Options`] and any callbacks or limiters
+    /// must be supplied again when resuming. See [`EaseOffState`] for details.
+    pub fn state(&self) -> EaseOffState {
+        let summary = self.summary();
+
+        EaseOffState {
+            attempts: summary.attempts,
+            elapsed: summary.elapsed,
+            remaining: summary.remaining,
+            total_slept: self.total_slept,
+        }
+    }
+}
+
+impl Options {
+    /// Begin backing off from a [snapshot][EaseOff::state()] of a previous instance.
+    ///
+    /// The returned instance has made [`EaseOffState::attempts`] attempts, started
+    /// [`EaseOffState::elapsed`] ago, and has a deadline [`EaseOffState::remaining`] from now,
+    /// if any. The deadline is used as-is; [`Self::deadline_jitter()`] is not applied again.
+    ///
+    /// As the error from the last attempt is not part of the snapshot, the next attempt
+    /// is made immediately. Subsequent delays continue the schedule from the restored
+    /// number of attempts, as if the instance had never been interrupted.
+    ///
+    /// If the deadline elapsed before the snapshot was taken (`remaining` is zero),
+    /// one more attempt is still made, unless zero attempts are
+    /// [allowed][Self::allow_zero_attempts()].
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use ease_off::Options;
+    ///
+    /// const OPTIONS: Options = Options::new().initial_delay(Duration::from_millis(1));
+    ///
+    /// let mut ease_off = OPTIONS.start_timeout(Duration::from_secs(60));
+    ///
+    /// for _ in 0..3 {
+    ///     let _ = ease_off
+    ///         .try_blocking(|| Err::<(), _>("oops"))
+    ///         .or_retry_if(|_e| true);
+    /// }
+    ///
+    /// // e.g. persisted to a database before a restart.
+    /// let state = ease_off.state();
+    ///
+    /// let resumed = OPTIONS.resume::<&str>(state.clone());
+    ///
+    /// assert_eq!(resumed.num_attempts(), ease_off.num_attempts());
+    /// assert!(resumed.started_at().elapsed() >= state.elapsed);
+    ///
+    /// assert!(resumed.deadline().unwrap() <= Instant::now() + state.remaining.unwrap());
+    /// ```
+    pub fn resume<E>(&self, state: EaseOffState) -> EaseOff<E> {
+        let now = clock::now();
+
+        let mut ease_off = self.start(now, None);
+
+        ease_off.started_at = now.checked_sub(state.elapsed).unwrap_or(now);
+        ease_off.deadline = state
+            .remaining
+            .map(|remaining| clock::saturating_add(now, remaining));
+        ease_off.num_attempts = Saturating(state.attempts);
+        ease_off.total_slept = state.total_slept;
+        ease_off.resumed = true;
+
+        ease_off
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resume_continues_schedule() {
+        let mut ease_off = Options::new()
+            .initial_delay(Duration::from_millis(1))
+            .jitter(0.0)
+            .resume::<&str>(EaseOffState::new(3, Duration::ZERO, None))
+            .with_history(10);
+
+        for _ in 0..2 {
+            let _ = ease_off
+                .try_blocking(|| Err::<(), _>("oops"))
+                .or_retry_if(|_e| true);
+        }
+
+        // The first attempt after resuming is immediate, then the delay for attempt 3 (1ms * 2^2).
+        assert_eq!(*ease_off.realized_delays(), [Duration::from_millis(4)]);
+        assert_eq!(ease_off.num_attempts(), 4);
+    }
+}
@@ -0,0 +1,156 @@
+//! Helpers for testing code which uses this crate.
+//!
+//! Schedules involve random jitter and are calculated relative to an
+//! [`Instant`], which makes comparing them exactly impractical.
+//! These helpers compare the delays between attempts instead, within a tolerance.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use ease_off::testing::Schedule;
+//! use ease_off::Options;
+//!
+//! const OPTIONS: Options = Options::new()
+//!     .initial_delay(Duration::from_millis(100))
+//!     .jitter(0.1)
+//!     .max_delay(Duration::from_millis(400));
+//!
+//! let schedule = Schedule::new(&OPTIONS, 5, &mut rand::thread_rng());
+//!
+//! let expected = [0, 100, 200, 400, 400].map(Duration::from_millis);
+//!
+//! // Jitter may shorten each delay by up to 10%.
+//! assert!(schedule.approx_eq(expected, Duration::from_millis(40)));
+//!
+//! // Even without jitter, the delays are subject to floating-point rounding.
+//! let schedule = Schedule::without_jitter(&OPTIONS, 5);
+//! assert!(schedule.approx_eq(expected, Duration::from_micros(1)));
+//! ```
+
+use crate::core::EaseOffCore;
+use crate::Options;
+use rand::rngs::mock::StepRng;
+use rand::Rng;
+use std::time::{Duration, Instant};
+
+/// Returns `true` if `a` and `b` have the same length and each pair of delays
+/// differs by at most `tolerance`.
+pub fn delays_approx_eq(a: &[Duration], b: &[Duration], tolerance: Duration) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| a.abs_diff(b) <= tolerance)
+}
+
+/// The delays before a sequence of attempts, as calculated by [`EaseOffCore`].
+///
+/// The delay at index `n` corresponds to [`EaseOffCore::nth_retry_at()`] with the same `n`;
+/// an attempt made immediately has a delay of zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schedule(pub Vec<Duration>);
+
+impl Schedule {
+    /// Calculate the delays before the first `attempts` attempts using the given `Options`,
+    /// applying jitter using `rng`.
+    ///
+    /// Pass a seeded or mock [`Rng`] for a reproducible schedule.
+    pub fn new(options: &Options, attempts: u32, rng: &mut (impl Rng + ?Sized)) -> Self {
+        let core = EaseOffCore::new(options.clone());
+        let now = Instant::now();
+
+        Self(
+            (0..attempts)
+                .map(|n| nth_delay(&core, n, now, rng))
+                .collect(),
+        )
+    }
+
+    /// Calculate the delays before the first `attempts` attempts using the given `Options`,
+    /// without applying jitter.
+    ///
+    /// As jitter only ever shortens delays, this is the longest possible schedule.
+    ///
+    /// If [`Options::initial_immediate_probability()`] is set, the first attempt is delayed
+    /// unless the probability is `1.0`.
+    pub fn without_jitter(options: &Options, attempts: u32) -> Self {
+        let core = EaseOffCore::new(options.clone());
+        let now = Instant::now();
+
+        Self(
+            (0..attempts)
+                .map(|n| {
+                    let mut rng = if n == 0 && options.initial_immediate_probability.is_some() {
+                        // The first value is drawn for the probability check and must not pick
+                        // an immediate attempt; the rest must be zero so no jitter is applied.
+                        StepRng::new(u64::MAX, 1)
+                    } else {
+                        // Always yields zero, so no jitter is applied.
+                        StepRng::new(0, 0)
+                    };
+
+                    nth_delay(&core, n, now, &mut rng)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the delays in this schedule.
+    #[inline(always)]
+    pub fn delays(&self) -> &[Duration] {
+        &self.0
+    }
+
+    /// Returns `true` if `other` has the same number of delays and each differs from the delay
+    /// in this schedule by at most `tolerance`.
+    ///
+    /// See [`delays_approx_eq()`].
+    pub fn approx_eq(&self, other: impl AsRef<[Duration]>, tolerance: Duration) -> bool {
+        delays_approx_eq(&self.0, other.as_ref(), tolerance)
+    }
+}
+
+/// The delay before the `n`th attempt, as scheduled by [`EaseOffCore::nth_retry_at_detailed()`].
+fn nth_delay(core: &EaseOffCore, n: u32, now: Instant, rng: &mut (impl Rng + ?Sized)) -> Duration {
+    core.nth_retry_at_detailed(n, now, None, rng)
+        .expect("BUG: retry cannot be after a deadline of `None`")
+        .map_or(Duration::ZERO, |retry| retry.delay)
+}
+
+impl AsRef<[Duration]> for Schedule {
+    #[inline(always)]
+    fn as_ref(&self) -> &[Duration] {
+        &self.0
+    }
+}
+
+impl From<Vec<Duration>> for Schedule {
+    #[inline(always)]
+    fn from(delays: Vec<Duration>) -> Self {
+        Self(delays)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn without_jitter_delays_first_attempt() {
+        let options = Options::new()
+            .initial_delay(Duration::from_millis(150))
+            .initial_immediate_probability(0.5)
+            .quantize_delay(Duration::from_secs(1));
+
+        let schedule = Schedule::without_jitter(&options, 3);
+        assert_eq!(schedule.delays(), [1, 1, 1].map(Duration::from_secs),);
+
+        let schedule = Schedule::without_jitter(&options.initial_immediate_probability(1.0), 3);
+        assert_eq!(schedule.delays(), [0, 1, 1].map(Duration::from_secs));
+    }
+
+    #[test]
+    fn new_matches_nth_retry_at() {
+        let options = Options::new()
+            .initial_delay(Duration::from_millis(150))
+            .quantize_delay(Duration::from_secs(1));
+
+        let schedule = Schedule::new(&options, 3, &mut rand::thread_rng());
+        assert_eq!(schedule.delays(), [0, 1, 1].map(Duration::from_secs));
+    }
+}